@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use std::hash::{BuildHasher, BuildHasherDefault, DefaultHasher, Hasher};
+
+use sfc_prng::{SfcBuildHasher, SfcHasher};
+use test::Bencher;
+
+#[bench]
+fn sfc_hasher_u64(b: &mut Bencher) {
+    let hash_builder = SfcBuildHasher::default();
+    b.iter(|| hash_builder.hash_one(test::black_box(u64::MAX)));
+}
+
+#[bench]
+fn sfc_hasher_bytes(b: &mut Bencher) {
+    let hash_builder = SfcBuildHasher::default();
+    let bytes = [u8::MAX; 1024];
+    b.iter(|| {
+        let mut hasher = hash_builder.build_hasher();
+        hasher.write(test::black_box(&bytes));
+        hasher.finish()
+    });
+}
+
+#[bench]
+fn sfc_hasher_new(b: &mut Bencher) {
+    b.iter(|| SfcHasher::new(test::black_box(Default::default())));
+}
+
+#[bench]
+fn sip_hasher_u64(b: &mut Bencher) {
+    let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+    b.iter(|| hash_builder.hash_one(test::black_box(u64::MAX)));
+}
+
+#[bench]
+fn sip_hasher_bytes(b: &mut Bencher) {
+    let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+    let bytes = [u8::MAX; 1024];
+    b.iter(|| {
+        let mut hasher = hash_builder.build_hasher();
+        hasher.write(test::black_box(&bytes));
+        hasher.finish()
+    });
+}
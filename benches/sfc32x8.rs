@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use sfc_prng::{Sfc32, Sfc32x8, rand_core::SeedableRng};
+use test::Bencher;
+
+#[bench]
+fn equality(b: &mut Bencher) {
+    b.iter(|| {
+        Sfc32x8::new(
+            [u32::default(); 8],
+            [u32::default(); 8],
+            [u32::default(); 8],
+            None,
+        ) == Sfc32x8::new(
+            [u32::default(); 8],
+            [u32::default(); 8],
+            [u32::default(); 8],
+            None,
+        )
+    });
+}
+
+#[bench]
+fn new(b: &mut Bencher) {
+    b.iter(|| {
+        Sfc32x8::new(
+            [u32::default(); 8],
+            [u32::default(); 8],
+            [u32::default(); 8],
+            None,
+        )
+    });
+}
+
+#[bench]
+fn new_u64(b: &mut Bencher) {
+    b.iter(|| Sfc32x8::new_u64([u64::default(); 8], None));
+}
+
+#[bench]
+fn next_u32x8(b: &mut Bencher) {
+    let mut rng = Sfc32x8::from_lanes([(); 8].map(|()| Sfc32::from_os_rng()));
+    b.iter(|| rng.next_u32x8());
+}
+
+#[bench]
+fn fill_u32x8(b: &mut Bencher) {
+    let mut rng = Sfc32x8::from_lanes([(); 8].map(|()| Sfc32::from_os_rng()));
+    let mut dst = [[u32::default(); 8]; 128];
+    b.iter(|| rng.fill_u32x8(&mut dst));
+}
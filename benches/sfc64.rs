@@ -39,6 +39,20 @@ fn next_u64(b: &mut Bencher) {
     b.iter(|| rng.next_u64());
 }
 
+#[bench]
+fn fill_u32(b: &mut Bencher) {
+    let mut rng = Sfc64::from_os_rng();
+    let mut dst = [u32::default(); 1024];
+    b.iter(|| rng.fill_u32(&mut dst));
+}
+
+#[bench]
+fn fill_u64(b: &mut Bencher) {
+    let mut rng = Sfc64::from_os_rng();
+    let mut dst = [u64::default(); 1024];
+    b.iter(|| rng.fill_u64(&mut dst));
+}
+
 #[bench]
 fn fill_bytes(b: &mut Bencher) {
     let mut rng = Sfc64::from_os_rng();
@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use sfc_prng::{Sfc64, Sfc64x4, rand_core::SeedableRng};
+use test::Bencher;
+
+#[bench]
+fn equality(b: &mut Bencher) {
+    b.iter(|| {
+        Sfc64x4::new(
+            [u64::default(); 4],
+            [u64::default(); 4],
+            [u64::default(); 4],
+            None,
+        ) == Sfc64x4::new(
+            [u64::default(); 4],
+            [u64::default(); 4],
+            [u64::default(); 4],
+            None,
+        )
+    });
+}
+
+#[bench]
+fn new(b: &mut Bencher) {
+    b.iter(|| {
+        Sfc64x4::new(
+            [u64::default(); 4],
+            [u64::default(); 4],
+            [u64::default(); 4],
+            None,
+        )
+    });
+}
+
+#[bench]
+fn new_u64(b: &mut Bencher) {
+    b.iter(|| Sfc64x4::new_u64([u64::default(); 4], None));
+}
+
+#[bench]
+fn next_u64x4(b: &mut Bencher) {
+    let mut rng = Sfc64x4::from_lanes([(); 4].map(|()| Sfc64::from_os_rng()));
+    b.iter(|| rng.next_u64x4());
+}
+
+#[bench]
+fn fill_u64x4(b: &mut Bencher) {
+    let mut rng = Sfc64x4::from_lanes([(); 4].map(|()| Sfc64::from_os_rng()));
+    let mut dst = [[u64::default(); 4]; 256];
+    b.iter(|| rng.fill_u64x4(&mut dst));
+}
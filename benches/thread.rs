@@ -0,0 +1,41 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![feature(test)]
+
+extern crate test;
+
+use sfc_prng::{Sfc64, rand_core::RngCore, thread_rng, with_thread_rng};
+use test::Bencher;
+
+#[bench]
+fn next_u64(b: &mut Bencher) {
+    let mut rng = Sfc64::new_u64(0, None);
+    b.iter(|| rng.next_u64());
+}
+
+#[bench]
+fn thread_rng_next_u64(b: &mut Bencher) {
+    let mut rng = thread_rng();
+    b.iter(|| rng.next_u64());
+}
+
+#[bench]
+fn with_thread_rng_next_u64(b: &mut Bencher) {
+    b.iter(|| with_thread_rng(RngCore::next_u64));
+}
+
+#[bench]
+fn fill_bytes(b: &mut Bencher) {
+    let mut rng = Sfc64::new_u64(0, None);
+    let mut buf = [u8::default(); 1024];
+    b.iter(|| rng.fill_bytes(&mut buf));
+}
+
+#[bench]
+fn thread_rng_fill_bytes(b: &mut Bencher) {
+    let mut rng = thread_rng();
+    let mut buf = [u8::default(); 1024];
+    b.iter(|| rng.fill_bytes(&mut buf));
+}
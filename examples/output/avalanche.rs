@@ -0,0 +1,150 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Measuring the avalanche of the seed mixing.
+
+use anyhow::bail;
+use clap::{Args, ValueEnum, builder::PossibleValue};
+use sfc_prng::{Sfc64, analysis};
+
+use crate::Seed;
+
+#[derive(Debug, Args)]
+pub struct Opt {
+    /// Constructors to measure.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_delimiter(','),
+        default_values_t = analysis::Constructor::ALL.map(Constructor),
+        ignore_case(true)
+    )]
+    constructor: Vec<Constructor>,
+
+    /// The largest number of mixing rounds to measure.
+    ///
+    /// All numbers of rounds from 0 to this are measured.
+    #[arg(short, long, default_value_t = 20)]
+    max_rounds: u32,
+
+    /// Number of random seeds for each number of rounds.
+    #[arg(short('n'), long, default_value_t = 1024)]
+    samples: u64,
+
+    /// Random seed of the generator which draws the seeds.
+    #[arg(short, long, default_value_t = Seed(0))]
+    seed: Seed,
+}
+
+/// A constructor which can be given on the command line.
+///
+/// The variants are taken from [`analysis::Constructor::ALL`], and the match
+/// which names them is exhaustive, so they cannot drift apart.
+#[derive(Clone, Copy, Debug)]
+struct Constructor(analysis::Constructor);
+
+impl ValueEnum for Constructor {
+    fn value_variants<'a>() -> &'a [Self] {
+        const VARIANTS: [Constructor; analysis::Constructor::ALL.len()] = {
+            let mut variants =
+                [Constructor(analysis::Constructor::ALL[0]); analysis::Constructor::ALL.len()];
+            let mut i = 0;
+            while i < variants.len() {
+                variants[i] = Constructor(analysis::Constructor::ALL[i]);
+                i += 1;
+            }
+            variants
+        };
+        &VARIANTS
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let name = match self.0 {
+            analysis::Constructor::Sfc32New => "sfc32-new",
+            analysis::Constructor::Sfc32NewU64 => "sfc32-new-u64",
+            analysis::Constructor::Sfc64New => "sfc64-new",
+            analysis::Constructor::Sfc64NewU64 => "sfc64-new-u64",
+        };
+        Some(PossibleValue::new(name).help(format!("`{}`.", self.0)))
+    }
+}
+
+/// The number of standard errors of the sampling by which the largest bias may
+/// deviate from 0 for a full avalanche.
+///
+/// A larger bias is unlikely for any number of pairs of seed and output bits
+/// that the constructors have.
+const MAX_BIAS_SIGMAS: f64 = 6.0;
+
+#[allow(clippy::cast_precision_loss)]
+pub fn run(opt: &Opt) -> anyhow::Result<()> {
+    if opt.samples == 0 {
+        bail!("at least one sample is required");
+    }
+
+    let mut rng = Sfc64::new_u64(*opt.seed, None);
+    let max_bias = MAX_BIAS_SIGMAS * 0.5 / (opt.samples as f64).sqrt();
+    // The constructors whose default number of rounds does not give full
+    // avalanche.
+    let mut failures = Vec::new();
+    for (i, &Constructor(constructor)) in opt.constructor.iter().enumerate() {
+        if i != 0 {
+            println!();
+        }
+        println!(
+            "{constructor} ({} seed bits, {} output bits, * is the default)",
+            constructor.seed_bits(),
+            constructor.output_bits()
+        );
+        println!(
+            "{:>7} {:>11} {:>10} {:>10} {:>9}",
+            "rounds", "mean flips", "min flips", "max flips", "max bias"
+        );
+        // The smallest number of rounds from which all larger numbers pass.
+        let mut required = None;
+        for rounds in 0..=opt.max_rounds {
+            let avalanche = constructor.avalanche(rounds, opt.samples, &mut rng);
+            if avalanche.max_bias() < max_bias {
+                required = required.or(Some(rounds));
+            } else {
+                required = None;
+            }
+            let mark = if rounds == constructor.default_rounds() {
+                "*"
+            } else {
+                " "
+            };
+            println!(
+                "{rounds:>6}{mark} {:>11.3} {:>10.3} {:>10.3} {:>9.4}",
+                avalanche.mean_flips(),
+                avalanche.min_flips(),
+                avalanche.max_flips(),
+                avalanche.max_bias()
+            );
+        }
+        match required {
+            Some(rounds) => {
+                println!("full avalanche (max bias < {max_bias:.4}) from {rounds} rounds");
+            }
+            None => println!(
+                "no full avalanche (max bias < {max_bias:.4}) with {} rounds",
+                opt.max_rounds
+            ),
+        }
+        let default = constructor.default_rounds();
+        if default <= opt.max_rounds && required.is_none_or(|r| r > default) {
+            failures.push(constructor);
+        }
+    }
+
+    if !failures.is_empty() {
+        let failures = failures.iter().map(ToString::to_string).collect::<Vec<_>>();
+        bail!(
+            "the default number of rounds of {} does not give full avalanche",
+            failures.join(", ")
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Testing the correlation between generators with neighbouring seeds.
+
+use anyhow::bail;
+use clap::Args;
+use sfc_prng::{
+    Sfc32, Sfc64,
+    quality::{self, Interleaved, TestResult},
+};
+
+use crate::{Rng, Seed};
+
+#[derive(Debug, Args)]
+pub struct Opt {
+    /// Random number generator to test.
+    ///
+    /// The generators are created by `new_u64`.
+    #[arg(value_enum, ignore_case(true))]
+    rng: Rng,
+
+    /// Number of generators to interleave.
+    #[arg(short('n'), long, default_value_t = 4096)]
+    streams: u64,
+
+    /// The seed of the first generator.
+    #[arg(short, long, default_value_t = Seed(0))]
+    start: Seed,
+
+    /// The difference between the seeds of neighbouring generators.
+    ///
+    /// For example, "0x100000000" changes only the upper half of the seed,
+    /// which is the third word of the state of sfc32.
+    #[arg(long, default_value_t = Seed(1))]
+    stride: Seed,
+
+    /// The largest number of mixing rounds to test.
+    ///
+    /// All numbers of rounds from 0 to this are tested.
+    #[arg(short, long, default_value_t = 12)]
+    max_rounds: u32,
+
+    /// Significance level.
+    ///
+    /// A test fails if its p-value is less than this.
+    #[arg(long, default_value_t = 1e-6)]
+    alpha: f64,
+}
+
+impl Opt {
+    /// Returns the seeds of the generators.
+    fn seeds(&self) -> impl Iterator<Item = u64> {
+        let (start, stride) = (*self.start, *self.stride);
+        (0..self.streams).map(move |i| start.wrapping_add(i.wrapping_mul(stride)))
+    }
+
+    /// Runs the quick tests on the interleaved outputs of the generators with
+    /// `rounds` mixing rounds.
+    fn check(&self, rounds: u32) -> [TestResult; 6] {
+        match self.rng {
+            Rng::Sfc32 => {
+                let rngs = self.seeds().map(|s| Sfc32::new_u64(s, Some(rounds)));
+                quality::quick_check(&mut Interleaved::new(rngs.collect()))
+            }
+            Rng::Sfc64 => {
+                let rngs = self.seeds().map(|s| Sfc64::new_u64(s, Some(rounds.into())));
+                quality::quick_check(&mut Interleaved::new(rngs.collect()))
+            }
+        }
+    }
+}
+
+pub fn run(opt: &Opt) -> anyhow::Result<()> {
+    if opt.streams == 0 {
+        bail!("at least one generator is required");
+    }
+
+    println!("{:>6} {:>12}  failed tests", "rounds", "min p-value");
+    // The smallest number of rounds from which all larger numbers pass.
+    let mut required = None;
+    for rounds in 0..=opt.max_rounds {
+        let results = opt.check(rounds);
+        let min = results
+            .iter()
+            .map(TestResult::p_value)
+            .fold(f64::INFINITY, f64::min);
+        let failed = results
+            .iter()
+            .filter(|r| r.p_value() < opt.alpha)
+            .map(TestResult::name)
+            .collect::<Vec<_>>();
+        let failed = if failed.is_empty() {
+            required = required.or(Some(rounds));
+            String::from("-")
+        } else {
+            required = None;
+            failed.join(", ")
+        };
+        println!("{rounds:>6} {min:>12.3e}  {failed}");
+    }
+
+    match required {
+        Some(rounds) => {
+            println!(
+                "no correlation is detected with {rounds} or more rounds (tested up to {})",
+                opt.max_rounds
+            );
+            Ok(())
+        }
+        None => bail!(
+            "correlation is detected even with {} rounds",
+            opt.max_rounds
+        ),
+    }
+}
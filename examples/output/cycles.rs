@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Analyzing the cycle structure of sfc8.
+
+use anyhow::bail;
+use clap::Args;
+use sfc_prng::{
+    Sfc64,
+    analysis::{Cycle, CycleStructure, Sfc8},
+    rand_core::RngCore,
+};
+
+use crate::Seed;
+
+#[derive(Debug, Args)]
+pub struct Opt {
+    /// Do not list the cycles.
+    #[arg(short, long)]
+    quiet: bool,
+
+    /// Number of random states whose cycles are walked one step at a time.
+    ///
+    /// The shortest cycle is always walked from a random state on it. Each
+    /// walk takes as many steps as the length of the cycle, which is several
+    /// seconds for the longest cycles with optimizations.
+    #[arg(short('n'), long, default_value_t = 2)]
+    samples: u64,
+
+    /// Random seed of the generator which draws the states.
+    #[arg(short, long, default_value_t = Seed(0))]
+    seed: Seed,
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn log2(x: u64) -> f64 {
+    (x as f64).log2()
+}
+
+/// Returns a random state on `cycle`.
+fn state_on(cycle: &Cycle, rng: &mut Sfc64) -> Sfc8 {
+    let mut state = cycle.start();
+    for _ in 0..rng.next_u64() % cycle.length() {
+        state.next_u8();
+    }
+    state
+}
+
+pub fn run(opt: &Opt) -> anyhow::Result<()> {
+    let structure = CycleStructure::sfc8();
+    let states = structure.states();
+    println!(
+        "states:         2^{:.2} in {} cycles",
+        log2(states),
+        structure.cycles().len()
+    );
+    println!(
+        "average period: 2^{:.2} (about 2^{:.0} for a random permutation)",
+        structure.average_period().log2(),
+        log2(states / 2)
+    );
+    println!(
+        "minimum period: 2^{:.2} (at least 2^{:.0} is guaranteed by the counter)",
+        log2(structure.min_length()),
+        log2(Sfc8::MIN_PERIOD)
+    );
+    println!("maximum period: 2^{:.2}", log2(structure.max_length()));
+
+    println!();
+    println!("{:<16} {:>6}", "length", "cycles");
+    for (i, &count) in structure.length_distribution().iter().enumerate() {
+        if count != 0 {
+            println!("{:<16} {count:>6}", format!("[2^{i}, 2^{})", i + 1));
+        }
+    }
+
+    if !opt.quiet {
+        println!();
+        println!("{:<20} {:>12}", "start state", "length");
+        for cycle in structure.cycles() {
+            let [a, b, c, counter] = cycle.start().state();
+            println!(
+                "{:<20} {:>12}",
+                format!("{a:#04x} {b:#04x} {c:#04x} {counter:#04x}"),
+                cycle.length()
+            );
+        }
+    }
+
+    // The cycles above are measured from a state whose counter is 0 to the
+    // next such state, so their lengths are multiples of the minimum period by
+    // construction. Walking them one step at a time checks this independently.
+    let mut rng = Sfc64::new_u64(*opt.seed, None);
+    let mut states = structure
+        .cycles()
+        .last()
+        .map(|c| state_on(c, &mut rng))
+        .into_iter()
+        .collect::<Vec<_>>();
+    states.extend((0..opt.samples).map(|_| Sfc8::from_state(rng.next_u32().to_le_bytes())));
+    println!();
+    println!("{:<20} {:>12}  result", "walked state", "length");
+    let mut failures = 0;
+    for state in &states {
+        let cycle = state.cycle();
+        let result = if !structure.cycles().contains(&cycle) {
+            "not a listed cycle"
+        } else if cycle.length() % Sfc8::MIN_PERIOD != 0 {
+            "not a multiple of the minimum period"
+        } else {
+            "ok"
+        };
+        if result != "ok" {
+            failures += 1;
+        }
+        let [a, b, c, counter] = state.state();
+        println!(
+            "{:<20} {:>12}  {result}",
+            format!("{a:#04x} {b:#04x} {c:#04x} {counter:#04x}"),
+            cycle.length()
+        );
+    }
+
+    if failures != 0 {
+        bail!(
+            "{failures} of {} walked cycles do not match the listed cycles or the minimum period \
+             of {}",
+            states.len(),
+            Sfc8::MIN_PERIOD
+        );
+    }
+    Ok(())
+}
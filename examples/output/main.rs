@@ -0,0 +1,660 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An example of generating random bytes. The result will be output to standard
+//! output.
+//!
+//! The arguments and the output are the same as `RNG_output` of `PractRand`, so
+//! `output sfc64 inf 0 | RNG_test stdin64` tests the sfc64 algorithm.
+
+#[cfg(feature = "analysis")]
+mod avalanche;
+#[cfg(feature = "quality")]
+mod correlation;
+#[cfg(feature = "analysis")]
+mod cycles;
+#[cfg(feature = "quality")]
+mod quality;
+mod vectors;
+
+#[cfg(feature = "serde")]
+use std::path::PathBuf;
+use std::{
+    fmt, fs,
+    io::{self, BufWriter, ErrorKind, Write},
+    num::ParseIntError,
+    ops::Deref,
+    path::Path,
+    str::FromStr,
+};
+
+use anyhow::{Context, bail};
+use byte_unit::Byte;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use sfc_prng::{
+    Sfc32, Sfc64,
+    rand_core::{RngCore, SeedableRng},
+};
+
+#[derive(Debug, Parser)]
+#[command(
+    version,
+    about,
+    args_conflicts_with_subcommands(true),
+    subcommand_negates_reqs(true)
+)]
+struct Opt {
+    /// Random number generator to use.
+    #[arg(value_enum, required(true), ignore_case(true))]
+    rng: Option<Rng>,
+
+    /// Number of bytes to generate.
+    ///
+    /// If [BYTES] is "inf", random bytes are generated until standard output is
+    /// closed.
+    #[arg(required(true))]
+    bytes: Option<Length>,
+
+    #[command(flatten)]
+    seeding: SeedingOpt,
+
+    /// Start from the state saved in the state file.
+    ///
+    /// This cannot be used with the seeding options.
+    #[cfg(feature = "serde")]
+    #[arg(
+        long,
+        value_name("FILE"),
+        conflicts_with_all(["seed", "method", "a", "b", "c", "rounds"])
+    )]
+    load_state: Option<PathBuf>,
+
+    /// Save the final state to the state file.
+    ///
+    /// [BYTES] must be a multiple of the word size of the generator, so that
+    /// the output resumed by --load-state continues this output without a gap.
+    /// If standard output is closed before all bytes are written, the state is
+    /// not saved and an error is returned.
+    #[cfg(feature = "serde")]
+    #[arg(long, value_name("FILE"))]
+    save_state: Option<PathBuf>,
+
+    /// Output format.
+    ///
+    /// The text formats format the same bytes as "raw". The formats of words
+    /// read the bytes as little-endian words, and ignore the trailing bytes
+    /// which do not make up a whole word.
+    #[arg(short, long, value_enum, default_value_t, ignore_case(true))]
+    format: Format,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Generate or verify test vectors.
+    Vectors(vectors::Opt),
+
+    /// Run quick statistical tests.
+    #[cfg(feature = "quality")]
+    Quality(quality::Opt),
+
+    /// Test the correlation between generators with neighbouring seeds.
+    #[cfg(feature = "quality")]
+    Correlation(correlation::Opt),
+
+    /// Find all cycles of sfc8, which is sfc with 8-bit words.
+    #[cfg(feature = "analysis")]
+    Cycles(cycles::Opt),
+
+    /// Measure the avalanche of the seed mixing for each number of rounds.
+    #[cfg(feature = "analysis")]
+    Avalanche(avalanche::Opt),
+}
+
+/// The options which select the seeding method and its arguments.
+#[derive(Debug, Args)]
+struct SeedingOpt {
+    /// Random seed to use with "new-u64" and "seed-from-u64" [default: 0].
+    ///
+    /// If [SEED] starts with "0x", it is considered to be hexadecimal,
+    /// otherwise it is considered to be decimal.
+    seed: Option<Seed>,
+
+    /// Seeding method to use.
+    #[arg(short, long, value_enum, default_value_t, ignore_case(true))]
+    method: Method,
+
+    /// The first word of the state to use with "new" and "from-seed" [default:
+    /// 0].
+    #[arg(short, long, value_name("VALUE"))]
+    a: Option<Seed>,
+
+    /// The second word of the state to use with "new" and "from-seed"
+    /// [default: 0].
+    #[arg(short, long, value_name("VALUE"))]
+    b: Option<Seed>,
+
+    /// The third word of the state to use with "new" and "from-seed" [default:
+    /// 0].
+    #[arg(short, long, value_name("VALUE"))]
+    c: Option<Seed>,
+
+    /// Number of rounds to use with "new" and "new-u64".
+    ///
+    /// If this is not specified, the default number of rounds of the method is
+    /// used.
+    #[arg(short, long)]
+    rounds: Option<u64>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+enum Rng {
+    /// sfc32.
+    Sfc32,
+
+    /// sfc64.
+    Sfc64,
+}
+
+impl fmt::Display for Rng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+enum Method {
+    /// `new`, which uses the words of the state.
+    New,
+
+    /// `new_u64`, which uses [SEED].
+    #[default]
+    NewU64,
+
+    /// `SeedableRng::from_seed`, which uses the words of the state as a
+    /// little-endian seed.
+    FromSeed,
+
+    /// `SeedableRng::seed_from_u64`, which uses [SEED].
+    SeedFromU64,
+}
+
+impl Method {
+    /// Returns `true` if the method uses the words of the state instead of
+    /// [SEED].
+    const fn uses_words(self) -> bool {
+        matches!(self, Self::New | Self::FromSeed)
+    }
+
+    /// Returns `true` if the method uses the number of rounds.
+    const fn uses_rounds(self) -> bool {
+        matches!(self, Self::New | Self::NewU64)
+    }
+
+    /// Checks that none of the given arguments is unused by the method.
+    ///
+    /// Each argument is its name and whether it is given.
+    fn check_unused(self, [seed, words, rounds]: [(&str, bool); 3]) -> anyhow::Result<()> {
+        for ((name, given), used) in [
+            (seed, !self.uses_words()),
+            (words, self.uses_words()),
+            (rounds, self.uses_rounds()),
+        ] {
+            if given && !used {
+                bail!("{name} cannot be used with `--method {self}`");
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum Format {
+    /// Raw bytes.
+    #[default]
+    Raw,
+
+    /// Plain hex dump, the same as `xxd -p`.
+    Hex,
+
+    /// Decimal u32 per line.
+    U32,
+
+    /// Hexadecimal u32 per line.
+    U32Hex,
+
+    /// Decimal u64 per line.
+    U64,
+
+    /// Hexadecimal u64 per line.
+    U64Hex,
+
+    /// f64 in the interval [0, 1) per line, which takes the upper 53 bits of
+    /// each u64.
+    F64,
+
+    /// C include file style, the same as `xxd -i`.
+    XxdI,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Length {
+    Inf,
+    Bytes(u64),
+}
+
+impl FromStr for Length {
+    type Err = byte_unit::ParseError;
+
+    fn from_str(length: &str) -> Result<Self, Self::Err> {
+        if length.eq_ignore_ascii_case("inf") {
+            Ok(Self::Inf)
+        } else {
+            length.parse::<Byte>().map(|b| Self::Bytes(b.as_u64()))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Seed(u64);
+
+impl Deref for Seed {
+    type Target = u64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl fmt::Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for Seed {
+    type Err = ParseIntError;
+
+    fn from_str(seed: &str) -> Result<Self, Self::Err> {
+        if seed.starts_with("0x") {
+            u64::from_str_radix(seed.trim_start_matches("0x"), 16)
+        } else {
+            seed.parse()
+        }
+        .map(Self)
+    }
+}
+
+/// The number of bytes generated at a time.
+const CHUNK_LEN: usize = 1 << 16;
+
+/// The same scale as `Sfc64::next_f64`.
+#[allow(clippy::cast_precision_loss)]
+const F64_SCALE: f64 = 1.0 / (1_u64 << 53) as f64;
+
+/// The number of bytes per line of `xxd -p`.
+const HEX_COLUMNS: usize = 30;
+
+/// The number of bytes per line of `xxd -i`.
+const XXD_I_COLUMNS: usize = 12;
+
+struct Output<W: Write> {
+    writer: W,
+    format: Format,
+    written: u64,
+}
+
+impl<W: Write> Output<W> {
+    const fn new(writer: W, format: Format) -> Self {
+        Self {
+            writer,
+            format,
+            written: 0,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    fn write(&mut self, bytes: &[u8]) -> io::Result<()> {
+        let w = &mut self.writer;
+        match self.format {
+            Format::Raw => w.write_all(bytes)?,
+            Format::Hex => {
+                for (i, b) in (self.written..).zip(bytes) {
+                    write!(w, "{b:02x}")?;
+                    if (i as usize + 1) % HEX_COLUMNS == 0 {
+                        writeln!(w)?;
+                    }
+                }
+            }
+            Format::U32 | Format::U32Hex => {
+                for word in bytes.chunks_exact(4) {
+                    let x = u32::from_le_bytes(word.try_into().unwrap());
+                    if matches!(self.format, Format::U32) {
+                        writeln!(w, "{x}")?;
+                    } else {
+                        writeln!(w, "{x:#010x}")?;
+                    }
+                }
+            }
+            Format::U64 | Format::U64Hex | Format::F64 => {
+                for word in bytes.chunks_exact(8) {
+                    let x = u64::from_le_bytes(word.try_into().unwrap());
+                    match self.format {
+                        Format::U64 => writeln!(w, "{x}")?,
+                        Format::U64Hex => writeln!(w, "{x:#018x}")?,
+                        _ => writeln!(w, "{}", (x >> 11) as f64 * F64_SCALE)?,
+                    }
+                }
+            }
+            Format::XxdI => {
+                for (i, b) in (self.written..).zip(bytes) {
+                    if i == 0 {
+                        write!(w, "  ")?;
+                    } else if i as usize % XXD_I_COLUMNS == 0 {
+                        write!(w, ",\n  ")?;
+                    } else {
+                        write!(w, ", ")?;
+                    }
+                    write!(w, "{b:#04x}")?;
+                }
+            }
+        }
+        self.written += bytes.len() as u64;
+        Ok(())
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn finish(mut self) -> io::Result<()> {
+        let partial_line = match self.format {
+            Format::Hex => self.written as usize % HEX_COLUMNS != 0,
+            Format::XxdI => self.written != 0,
+            _ => false,
+        };
+        if partial_line {
+            writeln!(self.writer)?;
+        }
+        self.writer.flush()
+    }
+}
+
+fn write_output(
+    mut rng: impl RngCore,
+    length: Length,
+    mut output: Output<impl Write>,
+) -> io::Result<()> {
+    let mut buf = vec![u8::default(); CHUNK_LEN];
+    let mut remaining = match length {
+        Length::Inf => None,
+        Length::Bytes(bytes) => Some(bytes),
+    };
+    loop {
+        let len = remaining.map_or(CHUNK_LEN, |r| {
+            usize::try_from(r).map_or(CHUNK_LEN, |r| r.min(CHUNK_LEN))
+        });
+        if len == 0 {
+            break output.finish();
+        }
+        let chunk = &mut buf[..len];
+        rng.fill_bytes(chunk);
+        output.write(chunk)?;
+        if let Some(r) = remaining.as_mut() {
+            *r -= len as u64;
+        }
+    }
+}
+
+/// Reads a JSON file which has the `version` field.
+fn read_versioned<T: DeserializeOwned>(path: &Path, kind: &str, version: u64) -> anyhow::Result<T> {
+    let file = fs::read(path).with_context(|| format!("could not read {}", path.display()))?;
+    let file = serde_json::from_slice::<serde_json::Value>(&file)
+        .with_context(|| format!("{} is not a valid {kind}", path.display()))?;
+    match file.get("version").and_then(serde_json::Value::as_u64) {
+        Some(v) if v == version => {}
+        Some(v) => bail!("unsupported {kind} version {v}"),
+        None => bail!("{} does not have the version", path.display()),
+    }
+    serde_json::from_value(file)
+        .with_context(|| format!("{} is not a valid {kind}", path.display()))
+}
+
+/// The version of the format of the state file.
+#[cfg(feature = "serde")]
+const STATE_FILE_VERSION: u64 = 1;
+
+/// The contents of the state file.
+#[cfg(feature = "serde")]
+#[derive(Debug, Deserialize, Serialize)]
+struct StateFile {
+    version: u64,
+
+    #[serde(flatten)]
+    state: State,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "rng", content = "state", rename_all = "lowercase")
+)]
+enum State {
+    Sfc32(Sfc32),
+    Sfc64(Sfc64),
+}
+
+#[cfg(feature = "serde")]
+impl State {
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        read_versioned::<StateFile>(path, "state file", STATE_FILE_VERSION).map(|f| f.state)
+    }
+
+    fn save(self, path: &Path) -> anyhow::Result<()> {
+        let file = StateFile {
+            version: STATE_FILE_VERSION,
+            state: self,
+        };
+        let mut file = serde_json::to_string_pretty(&file)?;
+        file.push('\n');
+        fs::write(path, file)
+            .with_context(|| format!("could not write the state to {}", path.display()))
+    }
+
+    const fn rng(&self) -> Rng {
+        match self {
+            Self::Sfc32(_) => Rng::Sfc32,
+            Self::Sfc64(_) => Rng::Sfc64,
+        }
+    }
+}
+
+/// A seeding method and its arguments.
+#[derive(Clone, Copy, Debug)]
+struct Seeding {
+    method: Method,
+    seed: u64,
+    words: [u64; 3],
+    rounds: Option<u64>,
+}
+
+impl Seeding {
+    fn sfc32(&self) -> anyhow::Result<Sfc32> {
+        let rounds = self
+            .rounds
+            .map(u32::try_from)
+            .transpose()
+            .context("the number of rounds must be a 32-bit value for sfc32")?;
+        let mut words = [u32::default(); 3];
+        for (w, word) in words.iter_mut().zip(self.words) {
+            *w = u32::try_from(word)
+                .context("the words of the state must be 32-bit values for sfc32")?;
+        }
+        let [a, b, c] = words;
+        let rng = match self.method {
+            Method::New => Sfc32::new(a, b, c, rounds),
+            Method::NewU64 => Sfc32::new_u64(self.seed, rounds),
+            Method::FromSeed => {
+                let mut seed = <Sfc32 as SeedableRng>::Seed::default();
+                for (chunk, word) in seed.chunks_exact_mut(4).zip([a, b, c]) {
+                    chunk.copy_from_slice(&word.to_le_bytes());
+                }
+                Sfc32::from_seed(seed)
+            }
+            Method::SeedFromU64 => Sfc32::seed_from_u64(self.seed),
+        };
+        Ok(rng)
+    }
+
+    fn sfc64(&self) -> Sfc64 {
+        let [a, b, c] = self.words;
+        match self.method {
+            Method::New => Sfc64::new(a, b, c, self.rounds),
+            Method::NewU64 => Sfc64::new_u64(self.seed, self.rounds),
+            Method::FromSeed => {
+                let mut seed = <Sfc64 as SeedableRng>::Seed::default();
+                for (chunk, word) in seed.chunks_exact_mut(8).zip([a, b, c]) {
+                    chunk.copy_from_slice(&word.to_le_bytes());
+                }
+                Sfc64::from_seed(seed)
+            }
+            Method::SeedFromU64 => Sfc64::seed_from_u64(self.seed),
+        }
+    }
+
+    fn rng(&self, rng: Rng) -> anyhow::Result<State> {
+        match rng {
+            Rng::Sfc32 => self.sfc32().map(State::Sfc32),
+            Rng::Sfc64 => Ok(State::Sfc64(self.sfc64())),
+        }
+    }
+}
+
+impl SeedingOpt {
+    /// Checks that only the options used by the seeding method are specified.
+    fn validate(&self) -> anyhow::Result<()> {
+        self.method.check_unused([
+            ("[SEED]", self.seed.is_some()),
+            (
+                "-a, -b and -c",
+                [self.a, self.b, self.c].iter().any(Option::is_some),
+            ),
+            ("--rounds", self.rounds.is_some()),
+        ])?;
+        Ok(())
+    }
+
+    fn seeding(&self) -> Seeding {
+        Seeding {
+            method: self.method,
+            seed: *self.seed.unwrap_or_default(),
+            words: [self.a, self.b, self.c].map(|w| *w.unwrap_or_default()),
+            rounds: self.rounds,
+        }
+    }
+}
+
+impl Opt {
+    /// Returns the state loaded by --load-state, or the state created by the
+    /// seeding options.
+    fn state(&self, rng: Rng) -> anyhow::Result<State> {
+        #[cfg(feature = "serde")]
+        if let Some(path) = &self.load_state {
+            let state = State::load(path)?;
+            if state.rng() != rng {
+                bail!(
+                    "{} has the state of {}, not {rng}",
+                    path.display(),
+                    state.rng()
+                );
+            }
+            return Ok(state);
+        }
+        self.seeding.seeding().rng(rng)
+    }
+
+    /// Checks that [BYTES] can be used with --save-state.
+    #[cfg(feature = "serde")]
+    fn validate_save_state(&self, rng: Rng, length: Length) -> anyhow::Result<()> {
+        if self.save_state.is_some() {
+            let word_bytes = match rng {
+                Rng::Sfc32 => 4,
+                Rng::Sfc64 => 8,
+            };
+            match length {
+                Length::Inf => bail!("--save-state cannot be used with an infinite output"),
+                Length::Bytes(bytes) if bytes % word_bytes != 0 => bail!(
+                    "[BYTES] must be a multiple of {word_bytes} to use --save-state with {rng}"
+                ),
+                Length::Bytes(_) => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    let opt = Opt::parse();
+
+    if let Some(command) = opt.command {
+        return match command {
+            Command::Vectors(opt) => vectors::run(&opt),
+            #[cfg(feature = "quality")]
+            Command::Quality(opt) => quality::run(&opt),
+            #[cfg(feature = "quality")]
+            Command::Correlation(opt) => correlation::run(&opt),
+            #[cfg(feature = "analysis")]
+            Command::Cycles(opt) => cycles::run(&opt),
+            #[cfg(feature = "analysis")]
+            Command::Avalanche(opt) => avalanche::run(&opt),
+        };
+    }
+    let (Some(rng), Some(length)) = (opt.rng, opt.bytes) else {
+        unreachable!("[RNG] and [BYTES] are required without a subcommand")
+    };
+    opt.seeding.validate()?;
+    #[cfg(feature = "serde")]
+    opt.validate_save_state(rng, length)?;
+
+    let mut state = opt.state(rng)?;
+
+    let output = Output::new(BufWriter::new(io::stdout().lock()), opt.format);
+    let result = match &mut state {
+        State::Sfc32(rng) => write_output(rng, length, output),
+        State::Sfc64(rng) => write_output(rng, length, output),
+    };
+    match result {
+        Err(err) if err.kind() == ErrorKind::BrokenPipe => {
+            // The generator has already run ahead of the bytes which were
+            // actually written, so its state would not continue the output.
+            #[cfg(feature = "serde")]
+            if opt.save_state.is_some() {
+                bail!("standard output was closed early, so the state was not saved");
+            }
+            return Ok(());
+        }
+        result => result.context("could not write random bytes to standard output")?,
+    }
+
+    #[cfg(feature = "serde")]
+    if let Some(path) = &opt.save_state {
+        state.save(path)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Running quick statistical tests.
+
+use anyhow::bail;
+use clap::Args;
+use sfc_prng::quality::{self, TestResult};
+
+use crate::{Rng, SeedingOpt, State};
+
+#[derive(Debug, Args)]
+pub struct Opt {
+    /// Random number generator to test.
+    #[arg(value_enum, ignore_case(true))]
+    rng: Rng,
+
+    #[command(flatten)]
+    seeding: SeedingOpt,
+
+    /// Significance level.
+    ///
+    /// A test fails if its p-value is less than this.
+    #[arg(long, default_value_t = 1e-6)]
+    alpha: f64,
+}
+
+/// Prints the results, and returns the number of failed tests.
+pub fn report(results: &[TestResult], alpha: f64) -> usize {
+    println!("{:<20} {:>16} {:>12}", "test", "statistic", "p-value");
+    let mut failures = 0;
+    for result in results {
+        let verdict = if result.p_value() < alpha {
+            failures += 1;
+            "FAIL"
+        } else {
+            "pass"
+        };
+        println!(
+            "{:<20} {:>16.6} {:>12.3e}  {verdict}",
+            result.name(),
+            result.statistic(),
+            result.p_value()
+        );
+    }
+    failures
+}
+
+pub fn run(opt: &Opt) -> anyhow::Result<()> {
+    opt.seeding.validate()?;
+    let results = match opt.seeding.seeding().rng(opt.rng)? {
+        State::Sfc32(mut rng) => quality::quick_check(&mut rng),
+        State::Sfc64(mut rng) => quality::quick_check(&mut rng),
+    };
+    let failures = report(&results, opt.alpha);
+    if failures != 0 {
+        bail!("{failures} of {} tests failed", results.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,444 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Generating and verifying test vectors.
+
+use std::{
+    fmt,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Context, bail};
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+use sfc_prng::rand_core::RngCore;
+
+use crate::{Method, Rng, Seed, Seeding, State, read_versioned};
+
+#[derive(Debug, Args)]
+pub struct Opt {
+    /// Random number generators to use.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter(','),
+        default_values_t = [Rng::Sfc32, Rng::Sfc64],
+        ignore_case(true)
+    )]
+    rng: Vec<Rng>,
+
+    /// Seeding methods to use.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        value_delimiter(','),
+        default_values_t = [Method::New, Method::NewU64, Method::FromSeed, Method::SeedFromU64],
+        ignore_case(true)
+    )]
+    method: Vec<Method>,
+
+    /// Random seeds to use with "new-u64" and "seed-from-u64".
+    #[arg(
+        short,
+        long,
+        value_delimiter(','),
+        default_values_t = [Seed(0), Seed(0x0123_4567_89ab_cdef)]
+    )]
+    seed: Vec<Seed>,
+
+    /// Words of the state to use with "new" and "from-seed".
+    #[arg(
+        short,
+        long,
+        value_name("A:B:C"),
+        value_delimiter(','),
+        default_values_t = [Words([0, 0, 0]), Words([1, 2, 3])]
+    )]
+    words: Vec<Words>,
+
+    /// Numbers of rounds to use with "new" and "new-u64".
+    ///
+    /// If this is not specified, the default number of rounds of the method is
+    /// used.
+    #[arg(short, long, value_delimiter(','))]
+    rounds: Vec<u64>,
+
+    /// Number of outputs of each test vector.
+    #[arg(short('n'), long, default_value_t = 16)]
+    count: usize,
+
+    /// Output format.
+    #[arg(short, long, value_enum, default_value_t, ignore_case(true))]
+    format: Format,
+
+    /// Verify the test vectors in the JSON file instead of generating them.
+    #[arg(
+        long,
+        value_name("FILE"),
+        conflicts_with_all(["rng", "method", "seed", "words", "rounds", "count", "format"])
+    )]
+    verify: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+enum Format {
+    /// JSON, which can be verified by --verify.
+    #[default]
+    Json,
+
+    /// Rust arrays.
+    Rust,
+}
+
+/// The words of the state, which are separated by ':'.
+#[derive(Clone, Copy, Debug)]
+struct Words([u64; 3]);
+
+impl fmt::Display for Words {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c] = self.0;
+        write!(f, "{a}:{b}:{c}")
+    }
+}
+
+impl FromStr for Words {
+    type Err = anyhow::Error;
+
+    fn from_str(words: &str) -> Result<Self, Self::Err> {
+        let words = words
+            .split(':')
+            .map(|w| w.parse::<Seed>().map(|w| *w))
+            .collect::<Result<Vec<_>, _>>()?;
+        words
+            .try_into()
+            .map(Self)
+            .map_err(|_| anyhow::anyhow!("the state must have 3 words"))
+    }
+}
+
+/// A [`u64`] value which is written as a hexadecimal string, since JSON
+/// numbers may not be able to represent all [`u64`] values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Hex(u64);
+
+impl Serialize for Hex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#x}", self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for Hex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .parse::<Seed>()
+            .map(|v| Self(*v))
+            .map_err(de::Error::custom)
+    }
+}
+
+/// The version of the format of the test vector file.
+const VECTOR_FILE_VERSION: u64 = 1;
+
+/// The contents of the test vector file.
+#[derive(Debug, Deserialize, Serialize)]
+struct VectorFile {
+    version: u64,
+    vectors: Vec<Vector>,
+}
+
+/// A test vector, which is the first outputs of a generator.
+///
+/// The outputs are the values of `next_u32` for sfc32, and the values of
+/// `next_u64` for sfc64.
+#[derive(Debug, Deserialize, Serialize)]
+struct Vector {
+    rng: Rng,
+    method: Method,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<Hex>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    words: Option<[Hex; 3]>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rounds: Option<u64>,
+
+    outputs: Vec<Hex>,
+}
+
+impl Vector {
+    fn new(rng: Rng, seeding: Seeding, count: usize) -> anyhow::Result<Self> {
+        let uses_words = seeding.method.uses_words();
+        let mut vector = Self {
+            rng,
+            method: seeding.method,
+            seed: (!uses_words).then_some(Hex(seeding.seed)),
+            words: uses_words.then_some(seeding.words.map(Hex)),
+            rounds: seeding.rounds,
+            outputs: Vec::new(),
+        };
+        vector.outputs = vector.generate(count)?;
+        Ok(vector)
+    }
+
+    /// Checks that the vector has exactly the arguments which its method uses,
+    /// and at least one output.
+    fn validate(&self) -> anyhow::Result<()> {
+        self.method.check_unused([
+            ("\"seed\"", self.seed.is_some()),
+            ("\"words\"", self.words.is_some()),
+            ("\"rounds\"", self.rounds.is_some()),
+        ])?;
+        if self.method.uses_words() && self.words.is_none() {
+            bail!("\"words\" is required by `--method {}`", self.method);
+        }
+        if !self.method.uses_words() && self.seed.is_none() {
+            bail!("\"seed\" is required by `--method {}`", self.method);
+        }
+        if self.outputs.is_empty() {
+            bail!("there are no outputs");
+        }
+        Ok(())
+    }
+
+    fn seeding(&self) -> Seeding {
+        Seeding {
+            method: self.method,
+            seed: self.seed.map_or_else(u64::default, |s| s.0),
+            words: self.words.map_or_else(Default::default, |w| w.map(|w| w.0)),
+            rounds: self.rounds,
+        }
+    }
+
+    fn generate(&self, count: usize) -> anyhow::Result<Vec<Hex>> {
+        let outputs = match self.seeding().rng(self.rng)? {
+            State::Sfc32(mut rng) => (0..count).map(|_| Hex(u64::from(rng.next_u32()))).collect(),
+            State::Sfc64(mut rng) => (0..count).map(|_| Hex(rng.next_u64())).collect(),
+        };
+        Ok(outputs)
+    }
+
+    /// Returns the Rust expression which creates the generator.
+    fn expr(&self) -> String {
+        let ty = match self.rng {
+            Rng::Sfc32 => "Sfc32",
+            Rng::Sfc64 => "Sfc64",
+        };
+        let seeding = self.seeding();
+        let [a, b, c] = seeding.words;
+        let rounds = seeding
+            .rounds
+            .map_or_else(|| String::from("None"), |r| format!("Some({r})"));
+        match self.method {
+            Method::New => format!("{ty}::new({a:#x}, {b:#x}, {c:#x}, {rounds})"),
+            Method::NewU64 => format!("{ty}::new_u64({:#x}, {rounds})", seeding.seed),
+            Method::FromSeed => {
+                format!("{ty}::from_seed(/* little-endian bytes of [{a:#x}, {b:#x}, {c:#x}] */)")
+            }
+            Method::SeedFromU64 => format!("{ty}::seed_from_u64({:#x})", seeding.seed),
+        }
+    }
+
+    fn write_rust(&self, index: usize, mut writer: impl Write) -> io::Result<()> {
+        let (ty, digits) = match self.rng {
+            Rng::Sfc32 => ("u32", 8),
+            Rng::Sfc64 => ("u64", 16),
+        };
+        writeln!(writer, "// {}", self.expr())?;
+        writeln!(
+            writer,
+            "static EXPECTED_{index}: [{ty}; {}] = [",
+            self.outputs.len()
+        )?;
+        for output in &self.outputs {
+            let hex = format!("{:0digits$x}", output.0);
+            let groups = hex
+                .as_bytes()
+                .chunks(4)
+                .map(|g| String::from_utf8_lossy(g))
+                .collect::<Vec<_>>();
+            writeln!(writer, "    0x{},", groups.join("_"))?;
+        }
+        writeln!(writer, "];")
+    }
+}
+
+fn generate(opt: &Opt) -> anyhow::Result<Vec<Vector>> {
+    if opt.count == 0 {
+        bail!("each test vector must have at least one output");
+    }
+    let mut vectors = Vec::new();
+    for &rng in &opt.rng {
+        for &method in &opt.method {
+            let rounds = if opt.rounds.is_empty() || !method.uses_rounds() {
+                vec![None]
+            } else {
+                opt.rounds.iter().copied().map(Some).collect()
+            };
+            let seedings = if method.uses_words() {
+                opt.words
+                    .iter()
+                    .map(|w| (u64::default(), w.0))
+                    .collect::<Vec<_>>()
+            } else {
+                opt.seed
+                    .iter()
+                    .map(|s| (**s, [u64::default(); 3]))
+                    .collect()
+            };
+            for &(seed, words) in &seedings {
+                for &rounds in &rounds {
+                    let seeding = Seeding {
+                        method,
+                        seed,
+                        words,
+                        rounds,
+                    };
+                    let vector = Vector::new(rng, seeding, opt.count)
+                        .with_context(|| format!("could not generate a test vector for {rng}"))?;
+                    vectors.push(vector);
+                }
+            }
+        }
+    }
+    Ok(vectors)
+}
+
+fn verify(path: &Path) -> anyhow::Result<()> {
+    let file = read_versioned::<VectorFile>(path, "test vector file", VECTOR_FILE_VERSION)?;
+    let mut mismatches = 0;
+    for (i, vector) in file.vectors.iter().enumerate() {
+        vector
+            .validate()
+            .with_context(|| format!("test vector {i} ({}) is not valid", vector.expr()))?;
+        let outputs = vector.generate(vector.outputs.len())?;
+        if let Some((j, (actual, expected))) = outputs
+            .iter()
+            .zip(&vector.outputs)
+            .enumerate()
+            .find(|(_, (a, e))| a != e)
+        {
+            mismatches += 1;
+            eprintln!(
+                "test vector {i} ({}) does not match: output {j} is {:#x}, but {:#x} is expected",
+                vector.expr(),
+                actual.0,
+                expected.0
+            );
+        }
+    }
+    if mismatches != 0 {
+        bail!(
+            "{mismatches} of {} test vectors do not match",
+            file.vectors.len()
+        );
+    }
+    println!("all {} test vectors match", file.vectors.len());
+    Ok(())
+}
+
+pub fn run(opt: &Opt) -> anyhow::Result<()> {
+    if let Some(path) = &opt.verify {
+        return verify(path);
+    }
+
+    let vectors = generate(opt)?;
+    let mut stdout = io::stdout().lock();
+    match opt.format {
+        Format::Json => {
+            let file = VectorFile {
+                version: VECTOR_FILE_VERSION,
+                vectors,
+            };
+            serde_json::to_writer_pretty(&mut stdout, &file)?;
+            writeln!(stdout)?;
+        }
+        Format::Rust => {
+            for (i, vector) in vectors.iter().enumerate() {
+                if i != 0 {
+                    writeln!(stdout)?;
+                }
+                vector.write_rust(i + 1, &mut stdout)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(json: &str) -> Vector {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn validate() {
+        assert!(
+            vector(
+                r#"{"rng":"sfc64","method":"new-u64","seed":"0x0","rounds":1,"outputs":["0x0"]}"#
+            )
+            .validate()
+            .is_ok()
+        );
+        assert!(
+            vector(r#"{"rng":"sfc32","method":"from-seed","words":["0x1","0x2","0x3"],"outputs":["0x0"]}"#)
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn validate_unused() {
+        for (json, name) in [
+            (
+                r#"{"rng":"sfc64","method":"seed-from-u64","seed":"0x0","rounds":99,"outputs":["0x0"]}"#,
+                "\"rounds\"",
+            ),
+            (
+                r#"{"rng":"sfc64","method":"new","seed":"0x0","words":["0x1","0x2","0x3"],"outputs":["0x0"]}"#,
+                "\"seed\"",
+            ),
+            (
+                r#"{"rng":"sfc64","method":"new-u64","seed":"0x0","words":["0x1","0x2","0x3"],"outputs":["0x0"]}"#,
+                "\"words\"",
+            ),
+        ] {
+            let err = vector(json).validate().unwrap_err();
+            assert!(
+                err.to_string()
+                    .starts_with(&format!("{name} cannot be used")),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn validate_missing() {
+        let err = vector(r#"{"rng":"sfc64","method":"new-u64","outputs":["0x0"]}"#)
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"seed\" is required by `--method new-u64`"
+        );
+        let err = vector(r#"{"rng":"sfc64","method":"from-seed","outputs":["0x0"]}"#)
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"words\" is required by `--method from-seed`"
+        );
+        let err = vector(r#"{"rng":"sfc64","method":"new-u64","seed":"0x0","outputs":[]}"#)
+            .validate()
+            .unwrap_err();
+        assert_eq!(err.to_string(), "there are no outputs");
+    }
+}
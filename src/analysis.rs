@@ -0,0 +1,758 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Analysis of the SFC algorithms and their seeding.
+//!
+//! The state of [`Sfc32`] and [`Sfc64`] is far too large to search, so the
+//! claims about their periods cannot be checked directly. [`Sfc8`] is the same
+//! algorithm with 8-bit words, whose 32-bit state can be searched exhaustively
+//! by [`CycleStructure::sfc8`]. The step function of the SFC algorithms is a
+//! bijection, so the state space splits into disjoint cycles, and the period of
+//! a generator is the length of the cycle which contains its state.
+//!
+//! For a state of `4w` bits, the documentation of the full-size generators
+//! claims an average period of approximately 2<sup>`4w-1`</sup> and a minimum
+//! period of 2<sup>`w`</sup>, which comes from the counter word. For [`Sfc8`],
+//! these are 2<sup>31</sup> and 2<sup>8</sup>.
+//!
+//! The constructors of the full-size generators mix up the seed by running the
+//! generator for a number of rounds, whose defaults are taken from `PractRand`.
+//! [`Constructor::avalanche`] measures how many bits of the first output flip
+//! when a bit of the seed flips after a given number of rounds, which shows
+//! how many rounds are needed for a seed to affect the whole output.
+//!
+//! # Examples
+//!
+//! ```
+//! use sfc_prng::analysis::Sfc8;
+//!
+//! let mut rng = Sfc8::from_state([0, 0, 0, 1]);
+//! assert_eq!(rng.next_u8(), 1);
+//! assert_eq!(rng.state(), [0, 0, 1, 2]);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use rand_core::RngCore;
+
+use crate::{Sfc32, Sfc64};
+
+/// A sfc8 random number generator, which is the SFC algorithm with 8-bit words.
+///
+/// This has a 32-bit state and outputs 8-bit random numbers. It is far too
+/// small for any practical use, and exists to analyze the SFC algorithm
+/// exhaustively.
+///
+/// `PractRand` does not define a variant with 8-bit words, so the shift and
+/// rotation constants are scaled down from those of sfc16, sfc32 and sfc64.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::analysis::Sfc8;
+/// #
+/// let mut rng = Sfc8::from_state([1, 2, 3, 4]);
+/// assert_eq!(rng.next_u8(), 7);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Sfc8 {
+    a: u8,
+    b: u8,
+    c: u8,
+    counter: u8,
+}
+
+impl Sfc8 {
+    /// The rotation of `c`.
+    pub const ROTATION: u32 = 3;
+
+    /// The right shift of `b`.
+    pub const RIGHT_SHIFT: u32 = 2;
+
+    /// The left shift of `c`.
+    pub const LEFT_SHIFT: u32 = 1;
+
+    /// The minimum period guaranteed by the counter word, which is
+    /// 2<sup>8</sup>.
+    pub const MIN_PERIOD: u64 = 1 << u8::BITS;
+
+    /// Creates a new `Sfc8` with the raw state words in the order `a`, `b`,
+    /// `c` and `counter`.
+    ///
+    /// Unlike the full-size generators, this does not mix up the state, so
+    /// any state can be selected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::analysis::Sfc8;
+    /// #
+    /// let rng = Sfc8::from_state([1, 2, 3, 4]);
+    /// assert_eq!(rng.state(), [1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_state([a, b, c, counter]: [u8; 4]) -> Self {
+        Self { a, b, c, counter }
+    }
+
+    /// Returns the raw state words in the order `a`, `b`, `c` and `counter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::analysis::Sfc8;
+    /// #
+    /// let mut rng = Sfc8::from_state([0, 0, 0, 0]);
+    /// rng.next_u8();
+    /// assert_eq!(rng.state(), [0, 0, 0, 1]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn state(&self) -> [u8; 4] {
+        [self.a, self.b, self.c, self.counter]
+    }
+
+    /// Returns the next random [`u8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::analysis::Sfc8;
+    /// #
+    /// let mut rng = Sfc8::from_state([0, 0, 0, 1]);
+    /// assert_eq!(rng.next_u8(), 1);
+    /// assert_eq!(rng.next_u8(), 2);
+    /// ```
+    #[inline]
+    pub const fn next_u8(&mut self) -> u8 {
+        let tmp = self.a.wrapping_add(self.b).wrapping_add(self.counter);
+        self.a = self.b ^ (self.b >> Self::RIGHT_SHIFT);
+        self.b = self.c.wrapping_add(self.c << Self::LEFT_SHIFT);
+        self.c = self.c.rotate_left(Self::ROTATION).wrapping_add(tmp);
+        self.counter = self.counter.wrapping_add(1);
+        tmp
+    }
+
+    /// Walks the cycle which contains this state one step at a time until it
+    /// returns to this state.
+    ///
+    /// Unlike [`CycleStructure::sfc8`], this does not rely on the counter word,
+    /// so it checks the cycles found by that and the minimum period
+    /// independently. This takes as many steps as the length of the cycle,
+    /// which is up to 2<sup>32</sup>.
+    ///
+    /// The start of the returned cycle is the state described in
+    /// [`Cycle::start`], or this state if the cycle has no state whose counter
+    /// is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::analysis::Sfc8;
+    /// #
+    /// let start = Sfc8::from_state([0x3e, 0xc0, 0x41, 0]);
+    /// let mut rng = start;
+    /// for _ in 0..100 {
+    ///     rng.next_u8();
+    /// }
+    /// let cycle = rng.cycle();
+    /// assert_eq!(cycle.start(), start);
+    /// assert_eq!(cycle.length(), 512);
+    /// ```
+    #[must_use]
+    pub fn cycle(&self) -> Cycle {
+        let target = u32::from_be_bytes(self.state());
+        let (mut rng, mut start, mut length) = (*self, u32::MAX, 0);
+        loop {
+            if rng.counter == 0 {
+                start = start.min(u32::from_be_bytes(rng.state()));
+            }
+            rng.next_u8();
+            length += 1;
+            if u32::from_be_bytes(rng.state()) == target {
+                break;
+            }
+        }
+        let start = if start == u32::MAX {
+            *self
+        } else {
+            Self::from_state(start.to_be_bytes())
+        };
+        Cycle { start, length }
+    }
+}
+
+/// A cycle of the state space of [`Sfc8`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    start: Sfc8,
+    length: u64,
+}
+
+impl Cycle {
+    /// Returns the first state of the cycle, which is the state with the
+    /// smallest `a`, `b` and `c` among the states of the cycle whose counter
+    /// is 0.
+    #[must_use]
+    #[inline]
+    pub const fn start(&self) -> Sfc8 {
+        self.start
+    }
+
+    /// Returns the number of states of the cycle, which is the period of a
+    /// generator whose state is on the cycle.
+    #[must_use]
+    #[inline]
+    pub const fn length(&self) -> u64 {
+        self.length
+    }
+}
+
+/// The cycles of the whole state space of a reduced-width SFC variant.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use sfc_prng::analysis::{CycleStructure, Sfc8};
+/// #
+/// let structure = CycleStructure::sfc8();
+/// assert_eq!(structure.states(), 1 << 32);
+/// assert!(structure.min_length() >= Sfc8::MIN_PERIOD);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleStructure {
+    cycles: Vec<Cycle>,
+}
+
+impl CycleStructure {
+    /// Finds all cycles of [`Sfc8`].
+    ///
+    /// Every cycle passes through a state whose counter is 0, so this steps
+    /// each of the 2<sup>24</sup> such states which are not yet visited
+    /// around its cycle, counting the steps. This takes 2<sup>32</sup> steps
+    /// in total, which is a few seconds with optimizations, and a 2 MiB table
+    /// of the visited states.
+    #[must_use]
+    pub fn sfc8() -> Self {
+        let cycles = find_cycles(1 << 24, |index| {
+            let [_, a, b, c] = index.to_be_bytes();
+            let mut rng = Sfc8::from_state([a, b, c, 0]);
+            let mut steps = 0;
+            loop {
+                rng.next_u8();
+                steps += 1;
+                if rng.counter == 0 {
+                    break;
+                }
+            }
+            let [a, b, c, _] = rng.state();
+            (u32::from_be_bytes([0, a, b, c]), steps)
+        })
+        .into_iter()
+        .map(|(index, length)| {
+            let [_, a, b, c] = index.to_be_bytes();
+            Cycle {
+                start: Sfc8::from_state([a, b, c, 0]),
+                length,
+            }
+        })
+        .collect();
+        Self::new(cycles)
+    }
+
+    fn new(mut cycles: Vec<Cycle>) -> Self {
+        cycles.sort_by_key(|c| core::cmp::Reverse(c.length));
+        Self { cycles }
+    }
+
+    /// Returns the cycles, from the longest to the shortest.
+    #[must_use]
+    #[inline]
+    pub fn cycles(&self) -> &[Cycle] {
+        &self.cycles
+    }
+
+    /// Returns the total number of states of all cycles.
+    #[must_use]
+    #[inline]
+    pub fn states(&self) -> u64 {
+        self.cycles.iter().map(Cycle::length).sum()
+    }
+
+    /// Returns the length of the shortest cycle, which is the minimum period.
+    #[must_use]
+    #[inline]
+    pub fn min_length(&self) -> u64 {
+        self.cycles.last().map_or_else(u64::default, Cycle::length)
+    }
+
+    /// Returns the length of the longest cycle, which is the maximum period.
+    #[must_use]
+    #[inline]
+    pub fn max_length(&self) -> u64 {
+        self.cycles.first().map_or_else(u64::default, Cycle::length)
+    }
+
+    /// Returns the average period of a generator with a uniformly random
+    /// state.
+    ///
+    /// A state is on a cycle with a probability proportional to the length of
+    /// the cycle, so this is the sum of the squares of the lengths divided by
+    /// the number of states. For a random permutation, this is approximately
+    /// a half of the number of states.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_period(&self) -> f64 {
+        let squares = self
+            .cycles
+            .iter()
+            .map(|c| {
+                let length = c.length as f64;
+                length * length
+            })
+            .sum::<f64>();
+        squares / self.states() as f64
+    }
+
+    /// Returns the number of cycles whose length is at least
+    /// 2<sup>`i`</sup> and less than 2<sup>`i+1`</sup> at each index `i`.
+    #[must_use]
+    pub fn length_distribution(&self) -> [u64; 64] {
+        let mut distribution = [u64::default(); 64];
+        for cycle in &self.cycles {
+            distribution[cycle.length.ilog2() as usize] += 1;
+        }
+        distribution
+    }
+
+    /// Returns [`true`] if every cycle satisfies the minimum period guaranteed
+    /// by the counter word.
+    ///
+    /// The counter returns to its value only after a multiple of
+    /// [`Sfc8::MIN_PERIOD`] steps, so the length of every cycle must be a
+    /// positive multiple of it.
+    ///
+    /// [`CycleStructure::sfc8`] counts the steps from a state whose counter is
+    /// 0 to the next such state, so its cycles satisfy this by construction.
+    /// [`Sfc8::cycle`] measures a cycle without the counter.
+    #[must_use]
+    #[inline]
+    pub fn satisfies_min_period(&self) -> bool {
+        self.cycles
+            .iter()
+            .all(|c| c.length != 0 && c.length % Sfc8::MIN_PERIOD == 0)
+    }
+}
+
+/// A constructor of the full-size generators which mixes up its seed.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::analysis::Constructor;
+/// #
+/// assert_eq!(Constructor::Sfc32New.default_rounds(), 15);
+/// assert_eq!(Constructor::Sfc32New.to_string(), "Sfc32::new");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Constructor {
+    /// [`Sfc32::new`], whose seed is the words `a`, `b` and `c`.
+    Sfc32New,
+
+    /// [`Sfc32::new_u64`], whose seed is a [`u64`].
+    Sfc32NewU64,
+
+    /// [`Sfc64::new`], whose seed is the words `a`, `b` and `c`.
+    Sfc64New,
+
+    /// [`Sfc64::new_u64`], whose seed is a [`u64`].
+    Sfc64NewU64,
+}
+
+impl Constructor {
+    /// All constructors.
+    pub const ALL: [Self; 4] = [
+        Self::Sfc32New,
+        Self::Sfc32NewU64,
+        Self::Sfc64New,
+        Self::Sfc64NewU64,
+    ];
+
+    /// Returns the number of rounds used when `rounds` is [`None`].
+    #[must_use]
+    #[inline]
+    pub const fn default_rounds(self) -> u32 {
+        match self {
+            Self::Sfc32New => 15,
+            Self::Sfc64New => 18,
+            Self::Sfc32NewU64 | Self::Sfc64NewU64 => 12,
+        }
+    }
+
+    /// Returns the number of bits of the seed.
+    #[must_use]
+    #[inline]
+    pub const fn seed_bits(self) -> u32 {
+        match self {
+            Self::Sfc32New => 3 * u32::BITS,
+            Self::Sfc64New => 3 * u64::BITS,
+            Self::Sfc32NewU64 | Self::Sfc64NewU64 => u64::BITS,
+        }
+    }
+
+    /// Returns the number of bits of the first output, which is a [`u32`] for
+    /// sfc32 and a [`u64`] for sfc64.
+    #[must_use]
+    #[inline]
+    pub const fn output_bits(self) -> u32 {
+        match self {
+            Self::Sfc32New | Self::Sfc32NewU64 => u32::BITS,
+            Self::Sfc64New | Self::Sfc64NewU64 => u64::BITS,
+        }
+    }
+
+    /// Returns the number of bits of each word of the seed.
+    const fn word_bits(self) -> u32 {
+        match self {
+            Self::Sfc32New => u32::BITS,
+            _ => u64::BITS,
+        }
+    }
+
+    /// Returns the first output of the generator created from `seed` with
+    /// `rounds` rounds.
+    #[allow(clippy::cast_possible_truncation)]
+    fn first_output(self, [a, b, c]: [u64; 3], rounds: u32) -> u64 {
+        match self {
+            Self::Sfc32New => {
+                u64::from(Sfc32::new(a as u32, b as u32, c as u32, Some(rounds)).next_u32())
+            }
+            Self::Sfc32NewU64 => u64::from(Sfc32::new_u64(a, Some(rounds)).next_u32()),
+            Self::Sfc64New => Sfc64::new(a, b, c, Some(rounds.into())).next_u64(),
+            Self::Sfc64NewU64 => Sfc64::new_u64(a, Some(rounds.into())).next_u64(),
+        }
+    }
+
+    /// Measures the avalanche of this constructor with `rounds` rounds.
+    ///
+    /// For each of `samples` seeds drawn from `rng`, this flips each bit of the
+    /// seed in turn, and counts the bits of the first output which flip.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `samples` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, analysis::Constructor};
+    /// #
+    /// let mut rng = Sfc64::new_u64(0, None);
+    /// let avalanche = Constructor::Sfc64NewU64.avalanche(12, 256, &mut rng);
+    /// assert!((avalanche.mean_flips() - 32.0).abs() < 1.0);
+    ///
+    /// // Without mixing, the first output does not depend on `c`.
+    /// let avalanche = Constructor::Sfc64New.avalanche(0, 256, &mut rng);
+    /// assert_eq!(avalanche.min_flips(), 0.0);
+    /// ```
+    pub fn avalanche<R: RngCore + ?Sized>(
+        self,
+        rounds: u32,
+        samples: u64,
+        rng: &mut R,
+    ) -> Avalanche {
+        assert_ne!(samples, 0, "the number of samples must be greater than 0");
+        let (seed_bits, output_bits) = (self.seed_bits(), self.output_bits());
+        let mask = u64::MAX >> (u64::BITS - self.word_bits());
+        let mut flips = alloc::vec![u64::default(); (seed_bits * output_bits) as usize];
+        for _ in 0..samples {
+            let seed = [(); 3].map(|()| rng.next_u64() & mask);
+            let output = self.first_output(seed, rounds);
+            for i in 0..seed_bits {
+                let mut flipped = seed;
+                flipped[(i / self.word_bits()) as usize] ^= 1 << (i % self.word_bits());
+                let diff = output ^ self.first_output(flipped, rounds);
+                let row = &mut flips[(i * output_bits) as usize..][..output_bits as usize];
+                for (j, count) in (0..).zip(row) {
+                    *count += (diff >> j) & 1;
+                }
+            }
+        }
+        Avalanche {
+            seed_bits,
+            output_bits,
+            samples,
+            flips,
+        }
+    }
+}
+
+impl fmt::Display for Constructor {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Sfc32New => "Sfc32::new",
+            Self::Sfc32NewU64 => "Sfc32::new_u64",
+            Self::Sfc64New => "Sfc64::new",
+            Self::Sfc64NewU64 => "Sfc64::new_u64",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The result of [`Constructor::avalanche`].
+///
+/// For a good mixing, each bit of the output flips with a probability of 1/2
+/// whenever any bit of the seed flips, so a half of the output bits flip on
+/// average.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Avalanche {
+    seed_bits: u32,
+    output_bits: u32,
+    samples: u64,
+    flips: Vec<u64>,
+}
+
+impl Avalanche {
+    /// Returns the number of bits of the seed.
+    #[must_use]
+    #[inline]
+    pub const fn seed_bits(&self) -> u32 {
+        self.seed_bits
+    }
+
+    /// Returns the number of bits of the output.
+    #[must_use]
+    #[inline]
+    pub const fn output_bits(&self) -> u32 {
+        self.output_bits
+    }
+
+    /// Returns the number of seeds.
+    #[must_use]
+    #[inline]
+    pub const fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// Returns the probability that bit `output` of the output flips when bit
+    /// `seed` of the seed flips.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `seed` or `output` is out of range.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn probability(&self, seed: u32, output: u32) -> f64 {
+        assert!(seed < self.seed_bits && output < self.output_bits);
+        self.flips[(seed * self.output_bits + output) as usize] as f64 / self.samples as f64
+    }
+
+    /// Returns the average number of output bits which flip for each seed bit.
+    #[allow(clippy::cast_precision_loss)]
+    fn flips_per_seed_bit(&self) -> impl Iterator<Item = f64> + '_ {
+        self.flips
+            .chunks(self.output_bits as usize)
+            .map(|row| row.iter().sum::<u64>() as f64 / self.samples as f64)
+    }
+
+    /// Returns the average number of output bits which flip when a bit of the
+    /// seed flips.
+    #[must_use]
+    pub fn mean_flips(&self) -> f64 {
+        self.flips_per_seed_bit().sum::<f64>() / f64::from(self.seed_bits)
+    }
+
+    /// Returns the average number of output bits which flip when the least
+    /// influential bit of the seed flips.
+    #[must_use]
+    pub fn min_flips(&self) -> f64 {
+        self.flips_per_seed_bit().fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns the average number of output bits which flip when the most
+    /// influential bit of the seed flips.
+    #[must_use]
+    pub fn max_flips(&self) -> f64 {
+        self.flips_per_seed_bit().fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    /// Returns the largest deviation of [`Avalanche::probability`] from 1/2
+    /// over all pairs of seed and output bits.
+    ///
+    /// Even for a perfect mixing, this is not 0 due to the sampling error,
+    /// which is approximately `0.5 / sqrt(samples)` for each pair.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn max_bias(&self) -> f64 {
+        let samples = self.samples as f64;
+        self.flips
+            .iter()
+            .map(|&count| (count as f64 / samples - 0.5).abs())
+            .fold(f64::default(), f64::max)
+    }
+}
+
+/// Finds the cycles of a bijection on the `n` slices `0..n` of a state space.
+///
+/// `next` returns the next slice of a slice, and the number of steps from the
+/// one to the other. This returns the smallest slice and the total number of
+/// steps of each cycle.
+fn find_cycles(n: u32, mut next: impl FnMut(u32) -> (u32, u64)) -> Vec<(u32, u64)> {
+    let mut visited = alloc::vec![u64::default(); n.div_ceil(u64::BITS) as usize];
+    let mut cycles = Vec::new();
+    for start in 0..n {
+        let (word, bit) = ((start / u64::BITS) as usize, start % u64::BITS);
+        if visited[word] & (1 << bit) != 0 {
+            continue;
+        }
+        let (mut slice, mut length) = (start, 0);
+        loop {
+            visited[(slice / u64::BITS) as usize] |= 1 << (slice % u64::BITS);
+            let (next_slice, steps) = next(slice);
+            length += steps;
+            slice = next_slice;
+            if slice == start {
+                break;
+            }
+        }
+        cycles.push((start, length));
+    }
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn next_u8() {
+        let mut rng = Sfc8::from_state([0, 0, 0, 1]);
+        assert_eq!(rng.next_u8(), 1);
+        assert_eq!(rng.state(), [0, 0, 1, 2]);
+        assert_eq!(rng.next_u8(), 2);
+        assert_eq!(rng.state(), [0, 3, 10, 3]);
+
+        let mut rng = Sfc8::from_state([0xff, 0x80, 0x81, 0xfe]);
+        assert_eq!(rng.next_u8(), 0x7d);
+        assert_eq!(rng.state(), [0xa0, 0x83, 0x89, 0xff]);
+    }
+
+    #[test]
+    fn cycle() {
+        for (start, length) in [([0x3e, 0xc0, 0x41, 0], 512), ([0x02, 0x73, 0xc1, 0], 4352)] {
+            let start = Sfc8::from_state(start);
+            let expected = Cycle { start, length };
+            assert_eq!(start.cycle(), expected);
+            let mut rng = start;
+            for _ in 0..300 {
+                rng.next_u8();
+            }
+            assert_eq!(rng.cycle(), expected);
+        }
+    }
+
+    #[test]
+    fn find_cycles() {
+        // The permutation (0 3 5) (1) (2 4).
+        const NEXT: [u32; 6] = [3, 1, 4, 5, 2, 0];
+
+        let cycles = super::find_cycles(6, |slice| (NEXT[slice as usize], 10));
+        assert_eq!(cycles, [(0, 30), (1, 10), (2, 20)]);
+    }
+
+    #[test]
+    fn cycle_structure() {
+        let cycle = |length| Cycle {
+            start: Sfc8::from_state([0, 0, 0, 0]),
+            length,
+        };
+        let structure = CycleStructure::new(alloc::vec![cycle(256), cycle(1 << 12), cycle(768)]);
+        assert_eq!(
+            structure
+                .cycles()
+                .iter()
+                .map(Cycle::length)
+                .collect::<Vec<_>>(),
+            [1 << 12, 768, 256]
+        );
+        assert_eq!(structure.states(), 5120);
+        assert_eq!(structure.min_length(), 256);
+        assert_eq!(structure.max_length(), 1 << 12);
+        assert!((structure.average_period() - 3404.8).abs() < 1e-9);
+        let distribution = structure.length_distribution();
+        assert_eq!(distribution[8..13], [1, 1, 0, 0, 1]);
+        assert_eq!(distribution.iter().sum::<u64>(), 3);
+        assert!(structure.satisfies_min_period());
+        assert!(!CycleStructure::new(alloc::vec![cycle(255)]).satisfies_min_period());
+    }
+
+    #[test]
+    #[ignore = "walks all 2^32 states of sfc8"]
+    fn sfc8_cycle_structure() {
+        let structure = CycleStructure::sfc8();
+        assert_eq!(structure.states(), 1 << 32);
+        assert_eq!(structure.cycles().len(), 18);
+        assert_eq!(structure.min_length(), 1 << 9);
+        assert!(structure.satisfies_min_period());
+        let shortest = structure.cycles().last().unwrap();
+        assert_eq!(shortest.start().cycle(), *shortest);
+    }
+
+    #[test]
+    fn constructor() {
+        for constructor in Constructor::ALL {
+            let seed = [1, 2, 3];
+            let rounds = constructor.default_rounds();
+            let expected = match constructor {
+                Constructor::Sfc32New => u64::from(Sfc32::new(1, 2, 3, None).next_u32()),
+                Constructor::Sfc32NewU64 => u64::from(Sfc32::new_u64(1, None).next_u32()),
+                Constructor::Sfc64New => Sfc64::new(1, 2, 3, None).next_u64(),
+                Constructor::Sfc64NewU64 => Sfc64::new_u64(1, None).next_u64(),
+            };
+            assert_eq!(constructor.first_output(seed, rounds), expected);
+        }
+        assert_eq!(Constructor::Sfc64NewU64.to_string(), "Sfc64::new_u64");
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn avalanche_without_mixing() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let avalanche = Constructor::Sfc32New.avalanche(0, 64, &mut rng);
+        assert_eq!((avalanche.seed_bits(), avalanche.output_bits()), (96, 32));
+        assert_eq!(avalanche.samples(), 64);
+        // The first output is `a + b + counter`, so the lowest bit of `a` always
+        // flips the lowest bit of the output, and `c` does not affect it.
+        assert_eq!(avalanche.probability(0, 0), 1.0);
+        assert_eq!(avalanche.probability(64, 0), 0.0);
+        assert_eq!(avalanche.min_flips(), 0.0);
+        assert_eq!(avalanche.max_bias(), 0.5);
+    }
+
+    #[test]
+    fn avalanche_with_default_rounds() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        for constructor in Constructor::ALL {
+            let rounds = constructor.default_rounds();
+            let avalanche = constructor.avalanche(rounds, 256, &mut rng);
+            let half = f64::from(constructor.output_bits()) / 2.0;
+            assert!((avalanche.mean_flips() - half).abs() < 0.5, "{constructor}");
+            assert!(avalanche.min_flips() > half - 2.0, "{constructor}");
+            assert!(avalanche.max_flips() < half + 2.0, "{constructor}");
+            assert!(avalanche.max_bias() < 0.2, "{constructor}");
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of samples must be greater than 0")]
+    fn avalanche_without_samples() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let _ = Constructor::Sfc64NewU64.avalanche(12, 0, &mut rng);
+    }
+}
@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Block-based cores of the SFC random number generators.
+
+use rand_core::{SeedableRng, block::BlockRngCore};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Sfc32, Sfc64};
+
+/// The number of words generated at a time by [`Sfc32Core`] and [`Sfc64Core`].
+const BLOCK_LEN: usize = 16;
+
+/// A block-based core of the sfc32 random number generator.
+///
+/// This generates 16 [`u32`] values at a time, and is intended to be used with
+/// [`BlockRng`](rand_core::block::BlockRng).
+///
+/// `BlockRng<Sfc32Core>` produces exactly the same sequence as [`Sfc32`] for
+/// all of [`next_u32`], [`next_u64`] and [`fill_bytes`].
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{
+/// #     Sfc32, Sfc32Core,
+/// #     rand_core::{RngCore, SeedableRng, block::BlockRng},
+/// # };
+/// #
+/// let mut rng = BlockRng::<Sfc32Core>::from_seed([0; 12]);
+/// assert_eq!(rng.next_u32(), 0xfb52_c520);
+///
+/// let mut rng = BlockRng::new(Sfc32Core::from(Sfc32::new_u64(0, None)));
+/// assert_eq!(rng.next_u32(), 0x5146_76c3);
+/// ```
+///
+/// [`next_u32`]: rand_core::RngCore::next_u32
+/// [`next_u64`]: rand_core::RngCore::next_u64
+/// [`fill_bytes`]: rand_core::RngCore::fill_bytes
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Sfc32Core(Sfc32);
+
+impl BlockRngCore for Sfc32Core {
+    type Item = u32;
+    type Results = [u32; BLOCK_LEN];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Results) {
+        self.0.fill_u32(results);
+    }
+}
+
+impl SeedableRng for Sfc32Core {
+    type Seed = <Sfc32 as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Sfc32::from_seed(seed).into()
+    }
+}
+
+impl From<Sfc32> for Sfc32Core {
+    #[inline]
+    fn from(rng: Sfc32) -> Self {
+        Self(rng)
+    }
+}
+
+/// A block-based core of the sfc64 random number generator.
+///
+/// This generates 16 [`u64`] values at a time, and is intended to be used with
+/// [`BlockRng64`](rand_core::block::BlockRng64).
+///
+/// `BlockRng64<Sfc64Core>` produces exactly the same sequence as [`Sfc64`] for
+/// [`next_u64`] and [`fill_bytes`]. Unlike [`Sfc64`], which discards the upper
+/// 32 bits of a [`u64`] value, [`next_u32`] returns the lower 32 bits of a
+/// [`u64`] value first, and then its upper 32 bits.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{
+/// #     Sfc64, Sfc64Core,
+/// #     rand_core::{RngCore, SeedableRng, block::BlockRng64},
+/// # };
+/// #
+/// let mut rng = BlockRng64::<Sfc64Core>::from_seed([0; 24]);
+/// assert_eq!(rng.next_u64(), 0xdb90_9c81_8901_599d);
+/// assert_eq!(rng.next_u32(), 0x6521_6f57);
+/// assert_eq!(rng.next_u32(), 0x8ffd_1953);
+///
+/// let mut rng = BlockRng64::new(Sfc64Core::from(Sfc64::new_u64(0, None)));
+/// assert_eq!(rng.next_u64(), 0x3acf_a029_e3cc_6041);
+/// ```
+///
+/// [`next_u32`]: rand_core::RngCore::next_u32
+/// [`next_u64`]: rand_core::RngCore::next_u64
+/// [`fill_bytes`]: rand_core::RngCore::fill_bytes
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Sfc64Core(Sfc64);
+
+impl BlockRngCore for Sfc64Core {
+    type Item = u64;
+    type Results = [u64; BLOCK_LEN];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Results) {
+        self.0.fill_u64(results);
+    }
+}
+
+impl SeedableRng for Sfc64Core {
+    type Seed = <Sfc64 as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Sfc64::from_seed(seed).into()
+    }
+}
+
+impl From<Sfc64> for Sfc64Core {
+    #[inline]
+    fn from(rng: Sfc64) -> Self {
+        Self(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{
+        RngCore,
+        block::{BlockRng, BlockRng64},
+    };
+
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let core = Sfc32Core::from_seed(Default::default());
+        assert_eq!(core.clone(), core);
+
+        let core = Sfc64Core::from_seed(Default::default());
+        assert_eq!(core.clone(), core);
+    }
+
+    #[test]
+    fn from_seed() {
+        assert_eq!(
+            Sfc32Core::from_seed(Default::default()),
+            Sfc32Core::from(Sfc32::from_seed(Default::default()))
+        );
+        assert_eq!(
+            Sfc64Core::from_seed(Default::default()),
+            Sfc64Core::from(Sfc64::from_seed(Default::default()))
+        );
+    }
+
+    #[test]
+    fn generate() {
+        {
+            let mut core = Sfc32Core::from_seed(Default::default());
+            let mut expected = Sfc32::from_seed(Default::default());
+            let mut results = Default::default();
+            for _ in 0..3 {
+                core.generate(&mut results);
+                for r in results {
+                    assert_eq!(r, expected.next_u32());
+                }
+            }
+        }
+        {
+            let mut core = Sfc64Core::from_seed(Default::default());
+            let mut expected = Sfc64::from_seed(Default::default());
+            let mut results = Default::default();
+            for _ in 0..3 {
+                core.generate(&mut results);
+                for r in results {
+                    assert_eq!(r, expected.next_u64());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn block_rng_next_u32() {
+        let mut rng = BlockRng::<Sfc32Core>::seed_from_u64(1);
+        let mut expected = Sfc32::seed_from_u64(1);
+        for _ in 0..(BLOCK_LEN * 3) {
+            assert_eq!(rng.next_u32(), expected.next_u32());
+        }
+    }
+
+    #[test]
+    fn block_rng_next_u64() {
+        let mut rng = BlockRng::<Sfc32Core>::seed_from_u64(1);
+        let mut expected = Sfc32::seed_from_u64(1);
+        // Start from an odd index so that some values straddle the blocks.
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        for _ in 0..(BLOCK_LEN * 3) {
+            assert_eq!(rng.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn block_rng_fill_bytes() {
+        let mut rng = BlockRng::<Sfc32Core>::seed_from_u64(1);
+        let mut expected = Sfc32::seed_from_u64(1);
+        for len in [0, 1, 3, 4, 5, 7, 8, 9, 63, 64, 65, 200] {
+            let mut dst = [u8::default(); 200];
+            let mut expected_dst = [u8::default(); 200];
+            rng.fill_bytes(&mut dst[..len]);
+            expected.fill_bytes(&mut expected_dst[..len]);
+            assert_eq!(dst, expected_dst);
+        }
+    }
+
+    #[test]
+    fn block_rng_64_next_u32() {
+        let mut rng = BlockRng64::<Sfc64Core>::from_seed(Default::default());
+        let mut expected = Sfc64::from_seed(Default::default());
+        for _ in 0..(BLOCK_LEN * 3) {
+            let e = expected.next_u64();
+            assert_eq!(u64::from(rng.next_u32()), e & u64::from(u32::MAX));
+            assert_eq!(u64::from(rng.next_u32()), e >> u32::BITS);
+        }
+    }
+
+    #[test]
+    fn block_rng_64_next_u64() {
+        let mut rng = BlockRng64::<Sfc64Core>::seed_from_u64(1);
+        let mut expected = Sfc64::seed_from_u64(1);
+        for _ in 0..(BLOCK_LEN * 3) {
+            assert_eq!(rng.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn block_rng_64_fill_bytes() {
+        let mut rng = BlockRng64::<Sfc64Core>::seed_from_u64(1);
+        let mut expected = Sfc64::seed_from_u64(1);
+        for len in [0, 1, 7, 8, 9, 15, 16, 17, 127, 128, 129, 400] {
+            let mut dst = [u8::default(); 400];
+            let mut expected_dst = [u8::default(); 400];
+            rng.fill_bytes(&mut dst[..len]);
+            expected.fill_bytes(&mut expected_dst[..len]);
+            assert_eq!(dst, expected_dst);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let core = Sfc64Core::from_seed(Default::default());
+
+        let json = serde_json::to_string(&core).unwrap();
+        assert_eq!(
+            json,
+            r#"{"a":1074220252016367073,"b":14747097319099466665,"c":17960713684764683274,"counter":19}"#
+        );
+        assert_eq!(serde_json::from_str::<Sfc64Core>(&json).unwrap(), core);
+    }
+}
@@ -0,0 +1,446 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Unbiased bounded integer generation.
+
+use core::ops::Range;
+
+use rand_core::RngCore;
+
+use crate::{Sfc32, Sfc64};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for usize {}
+}
+
+/// An integer type which can be generated by [`Sfc32::gen_below`],
+/// [`Sfc64::gen_below`] and their `gen_range` counterparts.
+///
+/// This trait is sealed and implemented for [`u32`], [`u64`] and [`usize`].
+///
+/// The values are generated by Daniel Lemire's nearly divisionless method:
+///
+/// - A [`u32`] bound `n` draws a [`u32`] value `x` from
+///   [`next_u32`](RngCore::next_u32), and computes the 64-bit product `x * n`.
+///   If the lower 32 bits of the product are less than `2^32 mod n`, `x` is
+///   rejected and drawn again. Otherwise, the upper 32 bits of the product are
+///   the result.
+/// - A [`u64`] bound does the same with [`next_u64`](RngCore::next_u64) and the
+///   128-bit product.
+/// - A [`usize`] bound is handled as a [`u32`] bound if it fits in [`u32`], and
+///   as a [`u64`] bound otherwise, so the result does not depend on the pointer
+///   width of the target.
+///
+/// The results are covered by the [value stability
+/// guarantee](crate#value-stability).
+pub trait BoundedInt: Copy + private::Sealed {
+    #[doc(hidden)]
+    fn sample_below<R: RngCore + ?Sized>(rng: &mut R, n: Self) -> Self;
+
+    #[doc(hidden)]
+    fn sample_range<R: RngCore + ?Sized>(rng: &mut R, range: Range<Self>) -> Self;
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+fn sample_below_u32<R: RngCore + ?Sized>(rng: &mut R, n: u32) -> u32 {
+    assert_ne!(n, 0, "the upper bound must be greater than 0");
+    let mut m = u64::from(rng.next_u32()) * u64::from(n);
+    if (m as u32) < n {
+        let threshold = n.wrapping_neg() % n;
+        while (m as u32) < threshold {
+            m = u64::from(rng.next_u32()) * u64::from(n);
+        }
+    }
+    (m >> u32::BITS) as u32
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[inline]
+fn sample_below_u64<R: RngCore + ?Sized>(rng: &mut R, n: u64) -> u64 {
+    assert_ne!(n, 0, "the upper bound must be greater than 0");
+    let mut m = u128::from(rng.next_u64()) * u128::from(n);
+    if (m as u64) < n {
+        let threshold = n.wrapping_neg() % n;
+        while (m as u64) < threshold {
+            m = u128::from(rng.next_u64()) * u128::from(n);
+        }
+    }
+    (m >> u64::BITS) as u64
+}
+
+impl BoundedInt for u32 {
+    #[inline]
+    fn sample_below<R: RngCore + ?Sized>(rng: &mut R, n: Self) -> Self {
+        sample_below_u32(rng, n)
+    }
+
+    #[inline]
+    fn sample_range<R: RngCore + ?Sized>(rng: &mut R, range: Range<Self>) -> Self {
+        assert!(range.start < range.end, "the range must not be empty");
+        range.start + sample_below_u32(rng, range.end - range.start)
+    }
+}
+
+impl BoundedInt for u64 {
+    #[inline]
+    fn sample_below<R: RngCore + ?Sized>(rng: &mut R, n: Self) -> Self {
+        sample_below_u64(rng, n)
+    }
+
+    #[inline]
+    fn sample_range<R: RngCore + ?Sized>(rng: &mut R, range: Range<Self>) -> Self {
+        assert!(range.start < range.end, "the range must not be empty");
+        range.start + sample_below_u64(rng, range.end - range.start)
+    }
+}
+
+impl BoundedInt for usize {
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn sample_below<R: RngCore + ?Sized>(rng: &mut R, n: Self) -> Self {
+        // The result is less than `n`, so it always fits in `usize`.
+        match u32::try_from(n) {
+            Ok(n) => sample_below_u32(rng, n) as Self,
+            Err(_) => sample_below_u64(rng, n as u64) as Self,
+        }
+    }
+
+    #[inline]
+    fn sample_range<R: RngCore + ?Sized>(rng: &mut R, range: Range<Self>) -> Self {
+        assert!(range.start < range.end, "the range must not be empty");
+        range.start + Self::sample_below(rng, range.end - range.start)
+    }
+}
+
+impl Sfc32 {
+    /// Returns a random integer in the range `0..n` without bias.
+    ///
+    /// See [`BoundedInt`] for the algorithm. The output of this method is
+    /// covered by the [value stability guarantee](crate#value-stability).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// assert_eq!(rng.gen_below(6_u32), 5);
+    /// ```
+    #[inline]
+    pub fn gen_below<T: BoundedInt>(&mut self, n: T) -> T {
+        T::sample_below(self, n)
+    }
+
+    /// Returns a random integer in the given range without bias.
+    ///
+    /// See [`BoundedInt`] for the algorithm. The output of this method is
+    /// covered by the [value stability guarantee](crate#value-stability).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// assert_eq!(rng.gen_range(1_u32..7), 6);
+    /// ```
+    #[inline]
+    pub fn gen_range<T: BoundedInt>(&mut self, range: Range<T>) -> T {
+        T::sample_range(self, range)
+    }
+}
+
+impl Sfc64 {
+    /// Returns a random integer in the range `0..n` without bias.
+    ///
+    /// See [`BoundedInt`] for the algorithm. The output of this method is
+    /// covered by the [value stability guarantee](crate#value-stability).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// assert_eq!(rng.gen_below(6_u64), 5);
+    /// ```
+    #[inline]
+    pub fn gen_below<T: BoundedInt>(&mut self, n: T) -> T {
+        T::sample_below(self, n)
+    }
+
+    /// Returns a random integer in the given range without bias.
+    ///
+    /// See [`BoundedInt`] for the algorithm. The output of this method is
+    /// covered by the [value stability guarantee](crate#value-stability).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// assert_eq!(rng.gen_range(1_u64..7), 6);
+    /// ```
+    #[inline]
+    pub fn gen_range<T: BoundedInt>(&mut self, range: Range<T>) -> T {
+        T::sample_range(self, range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS_U32: [u32; 16] = [
+        0x0000_0001,
+        0x0000_0002,
+        0x0000_0003,
+        0x0000_0006,
+        0x0000_0007,
+        0x0000_000a,
+        0x0000_0064,
+        0x0000_03e8,
+        0x0001_0000,
+        0x5555_5556,
+        0x8000_0000,
+        0x8000_0001,
+        0xc000_0001,
+        0xffff_fffe,
+        0xffff_ffff,
+        0x1234_5678,
+    ];
+    const BOUNDS_U64: [u64; 16] = [
+        0x0000_0000_0000_0001,
+        0x0000_0000_0000_0002,
+        0x0000_0000_0000_0006,
+        0x0000_0000_0000_03e8,
+        0x0000_0000_ffff_ffff,
+        0x0000_0001_0000_0000,
+        0x0000_0001_0000_0001,
+        0x5555_5555_5555_5556,
+        0x8000_0000_0000_0000,
+        0x8000_0000_0000_0001,
+        0xc000_0000_0000_0001,
+        0xffff_ffff_ffff_fffe,
+        0xffff_ffff_ffff_ffff,
+        0x0123_4567_89ab_cdef,
+        0x0000_0000_0000_0003,
+        0x0000_0100_0000_0000,
+    ];
+
+    static EXPECTED_SFC32_U32: [u32; 16] = [
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_0001,
+        0x0000_0005,
+        0x0000_0003,
+        0x0000_0009,
+        0x0000_0017,
+        0x0000_00db,
+        0x0000_5e70,
+        0x1afb_7952,
+        0x78f0_d851,
+        0x30b1_8197,
+        0x5249_1468,
+        0x5733_1c8b,
+        0x2aaf_9992,
+        0x0e82_76f1,
+    ];
+    static EXPECTED_SFC64_U32: [u32; 16] = [
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_0003,
+        0x0000_0005,
+        0x0000_0006,
+        0x0000_004d,
+        0x0000_0183,
+        0x0000_ca91,
+        0x33fc_1bee,
+        0x766a_7c2e,
+        0x71a3_5c89,
+        0x0b7e_48e3,
+        0x8d77_d757,
+        0x9b16_64a2,
+        0x0ac8_d5e0,
+    ];
+    static EXPECTED_SFC32_U64: [u64; 16] = [
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0001,
+        0x0000_0000_0000_0005,
+        0x0000_0000_0000_00db,
+        0x0000_0000_50f2_6bf7,
+        0x0000_0000_6163_032f,
+        0x0000_0000_6db6_c5e0,
+        0x0e3a_8886_7266_5ed9,
+        0x78d0_e758_6605_5458,
+        0x7f04_825d_44a9_6163,
+        0x50b9_9c7a_3688_552f,
+        0xfc32_58f2_5016_e489,
+        0x86cb_d949_a24b_d6e5,
+        0x0071_00a0_816f_7506,
+        0x0000_0000_0000_0002,
+        0x0000_00ae_4356_6fac,
+    ];
+    static EXPECTED_SFC64_U64: [u64; 16] = [
+        0x0000_0000_0000_0000,
+        0x0000_0000_0000_0001,
+        0x0000_0000_0000_0005,
+        0x0000_0000_0000_0230,
+        0x0000_0000_f986_5b00,
+        0x0000_0000_4655_5871,
+        0x0000_0000_6686_8678,
+        0x0ef5_c8d4_cbb8_a729,
+        0x0597_8c19_e548_e53c,
+        0x6894_4f06_f66a_7c2e,
+        0x2b42_01e1_2a75_0acd,
+        0x98d1_7cc0_0f53_0bd8,
+        0x13ac_08e9_8d77_d758,
+        0x00e6_fee6_7277_90f1,
+        0x0000_0000_0000_0001,
+        0x0000_006d_0be0_6bbf,
+    ];
+
+    #[test]
+    fn gen_below_u32() {
+        {
+            let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+            let expected = EXPECTED_SFC32_U32;
+            for (n, e) in BOUNDS_U32.into_iter().zip(expected) {
+                assert_eq!(rng.gen_below(n), e);
+            }
+        }
+        {
+            let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+            let expected = EXPECTED_SFC64_U32;
+            for (n, e) in BOUNDS_U32.into_iter().zip(expected) {
+                assert_eq!(rng.gen_below(n), e);
+            }
+        }
+    }
+
+    #[test]
+    fn gen_below_u64() {
+        {
+            let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+            let expected = EXPECTED_SFC32_U64;
+            for (n, e) in BOUNDS_U64.into_iter().zip(expected) {
+                assert_eq!(rng.gen_below(n), e);
+            }
+        }
+        {
+            let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+            let expected = EXPECTED_SFC64_U64;
+            for (n, e) in BOUNDS_U64.into_iter().zip(expected) {
+                assert_eq!(rng.gen_below(n), e);
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[test]
+    fn gen_below_usize() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        let mut expected = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for n in BOUNDS_U32 {
+            assert_eq!(rng.gen_below(n as usize), expected.gen_below(n) as usize);
+        }
+        #[cfg(target_pointer_width = "64")]
+        for n in BOUNDS_U64 {
+            let e = if let Ok(n) = u32::try_from(n) {
+                u64::from(expected.gen_below(n))
+            } else {
+                expected.gen_below(n)
+            };
+            assert_eq!(rng.gen_below(n as usize), e as usize);
+        }
+    }
+
+    #[test]
+    fn gen_below_one() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for _ in 0..16 {
+            assert_eq!(rng.gen_below(1_u32), 0);
+            assert_eq!(rng.gen_below(1_u64), 0);
+            assert_eq!(rng.gen_below(1_usize), 0);
+        }
+    }
+
+    #[test]
+    fn gen_below_bounds() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for n in BOUNDS_U32 {
+            for _ in 0..64 {
+                assert!(rng.gen_below(n) < n);
+            }
+        }
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for n in BOUNDS_U64 {
+            for _ in 0..64 {
+                assert!(rng.gen_below(n) < n);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the upper bound must be greater than 0")]
+    fn gen_below_zero() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        let _ = rng.gen_below(u64::default());
+    }
+
+    #[test]
+    fn gen_range() {
+        {
+            let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+            let expected = EXPECTED_SFC32_U32;
+            for (n, e) in BOUNDS_U32.into_iter().zip(expected) {
+                assert_eq!(rng.gen_range(0..n), e);
+            }
+        }
+        {
+            let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+            let expected = EXPECTED_SFC64_U64;
+            for (n, e) in BOUNDS_U64.into_iter().zip(expected) {
+                let start = u64::MAX - n;
+                assert_eq!(rng.gen_range(start..u64::MAX), start + e);
+            }
+        }
+        {
+            let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+            assert_eq!(rng.gen_range(1_usize..7), 4);
+            assert_eq!(rng.gen_range(3_u32..4), 3);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the range must not be empty")]
+    fn gen_range_empty() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let _ = rng.gen_range(1_u64..1);
+    }
+}
@@ -0,0 +1,333 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Floating-point number generation.
+
+use rand_core::RngCore;
+
+use crate::{Sfc32, Sfc64};
+
+impl Sfc64 {
+    /// Returns a random [`f64`] in the half-open interval `[0, 1)`.
+    ///
+    /// This takes the upper 53 bits of [`Sfc64::next_u64`] as an integer `x`,
+    /// and returns `x / 2^53`. The result is a multiple of 2<sup>-53</sup>, and
+    /// each possible value has the same probability.
+    ///
+    /// The output of this method is covered by the [value stability
+    /// guarantee](crate#value-stability).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// assert_eq!(rng.next_f64(), 0.857_675_344_109_343_5);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub fn next_f64(&mut self) -> f64 {
+        const SCALE: f64 = 1.0 / (1_u64 << 53) as f64;
+
+        (self.next_u64() >> 11) as f64 * SCALE
+    }
+
+    /// Returns a random [`f64`] in the open interval `(0, 1)`.
+    ///
+    /// This takes the upper 52 bits of [`Sfc64::next_u64`] as an integer `x`,
+    /// and returns `(x + 0.5) / 2^52`. The result is an odd multiple of
+    /// 2<sup>-53</sup>, and each possible value has the same probability.
+    ///
+    /// The output of this method is covered by the [value stability
+    /// guarantee](crate#value-stability).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// let x = rng.next_f64_open();
+    /// assert!(x > 0.0 && x < 1.0);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub fn next_f64_open(&mut self) -> f64 {
+        const SCALE: f64 = 1.0 / (1_u64 << 52) as f64;
+
+        ((self.next_u64() >> 12) as f64 + 0.5) * SCALE
+    }
+
+    /// Returns a random [`f64`] in the half-open interval `[0, 1)`, which can
+    /// be any representable value in this interval.
+    ///
+    /// Each value is returned with a probability proportional to the distance
+    /// to the next representable value, so smaller values are returned with
+    /// a finer granularity than by [`Sfc64::next_f64`]. This uses the method
+    /// described by Allen B. Downey in "Generating Pseudo-random Floating-Point
+    /// Values", without rounding up to the next binade.
+    ///
+    /// The result is computed as follows:
+    ///
+    /// 1. The upper 52 bits of the first [`Sfc64::next_u64`] become the
+    ///    significand.
+    /// 2. The biased exponent starts at 1022, that is, the interval `[0.5, 1)`.
+    ///    For each following [`Sfc64::next_u64`], the exponent is decreased by
+    ///    the number of its leading zeros. This stops at the first non-zero
+    ///    value, or when the exponent reaches 0.
+    /// 3. If the exponent has reached 0, the result is the subnormal number
+    ///    with this significand. Otherwise, the result is the normal number
+    ///    with this significand and exponent.
+    ///
+    /// In most cases, this consumes two values of [`Sfc64::next_u64`], and it
+    /// never consumes more than 17 values.
+    ///
+    /// The output of this method is covered by the [value stability
+    /// guarantee](crate#value-stability).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// assert_eq!(rng.next_f64_full_precision(), 0.928_837_672_054_671_7);
+    /// ```
+    #[inline]
+    pub fn next_f64_full_precision(&mut self) -> f64 {
+        const SIGNIFICAND_BITS: u32 = f64::MANTISSA_DIGITS - 1;
+
+        let significand = self.next_u64() >> (u64::BITS - SIGNIFICAND_BITS);
+        let mut exponent = u64::from(f64::MAX_EXP.unsigned_abs()) - 2;
+        loop {
+            let bits = self.next_u64();
+            exponent = exponent.saturating_sub(bits.leading_zeros().into());
+            if bits != 0 || exponent == 0 {
+                break;
+            }
+        }
+        f64::from_bits((exponent << SIGNIFICAND_BITS) | significand)
+    }
+}
+
+impl Sfc32 {
+    /// Returns a random [`f32`] in the half-open interval `[0, 1)`.
+    ///
+    /// This takes the upper 24 bits of [`Sfc32::next_u32`] as an integer `x`,
+    /// and returns `x / 2^24`. The result is a multiple of 2<sup>-24</sup>, and
+    /// each possible value has the same probability.
+    ///
+    /// The output of this method is covered by the [value stability
+    /// guarantee](crate#value-stability).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// assert_eq!(rng.next_f32(), 0.981_731_7);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub fn next_f32(&mut self) -> f32 {
+        const SCALE: f32 = 1.0 / (1_u32 << 24) as f32;
+
+        (self.next_u32() >> 8) as f32 * SCALE
+    }
+
+    /// Returns a random [`f32`] in the open interval `(0, 1)`.
+    ///
+    /// This takes the upper 23 bits of [`Sfc32::next_u32`] as an integer `x`,
+    /// and returns `(x + 0.5) / 2^23`. The result is an odd multiple of
+    /// 2<sup>-24</sup>, and each possible value has the same probability.
+    ///
+    /// The output of this method is covered by the [value stability
+    /// guarantee](crate#value-stability).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// let x = rng.next_f32_open();
+    /// assert!(x > 0.0 && x < 1.0);
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    #[inline]
+    pub fn next_f32_open(&mut self) -> f32 {
+        const SCALE: f32 = 1.0 / (1_u32 << 23) as f32;
+
+        ((self.next_u32() >> 9) as f32 + 0.5) * SCALE
+    }
+
+    /// Returns a random [`f32`] in the half-open interval `[0, 1)`, which can
+    /// be any representable value in this interval.
+    ///
+    /// This is the [`f32`] version of [`Sfc64::next_f64_full_precision`]:
+    ///
+    /// 1. The upper 23 bits of the first [`Sfc32::next_u32`] become the
+    ///    significand.
+    /// 2. The biased exponent starts at 126, that is, the interval `[0.5, 1)`.
+    ///    For each following [`Sfc32::next_u32`], the exponent is decreased by
+    ///    the number of its leading zeros. This stops at the first non-zero
+    ///    value, or when the exponent reaches 0.
+    /// 3. If the exponent has reached 0, the result is the subnormal number
+    ///    with this significand. Otherwise, the result is the normal number
+    ///    with this significand and exponent.
+    ///
+    /// In most cases, this consumes two values of [`Sfc32::next_u32`], and it
+    /// never consumes more than 5 values.
+    ///
+    /// The output of this method is covered by the [value stability
+    /// guarantee](crate#value-stability).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// assert_eq!(rng.next_f32_full_precision(), 0.247_716_46);
+    /// ```
+    #[inline]
+    pub fn next_f32_full_precision(&mut self) -> f32 {
+        const SIGNIFICAND_BITS: u32 = f32::MANTISSA_DIGITS - 1;
+
+        let significand = self.next_u32() >> (u32::BITS - SIGNIFICAND_BITS);
+        let mut exponent = f32::MAX_EXP.unsigned_abs() - 2;
+        loop {
+            let bits = self.next_u32();
+            exponent = exponent.saturating_sub(bits.leading_zeros());
+            if bits != 0 || exponent == 0 {
+                break;
+            }
+        }
+        f32::from_bits((exponent << SIGNIFICAND_BITS) | significand)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_f64() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for e in [
+            0x3feb_7213_9031_202b,
+            0x3fe1_ffa3_2a6c_a42d,
+            0x3fed_1895_abc4_b158,
+            0x3fe1_f1de_5913_fb6c,
+        ] {
+            assert_eq!(rng.next_f64().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_f64_bounds() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for _ in 0..1024 {
+            let x = rng.next_f64();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn next_f64_open() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for e in [
+            0x3feb_7213_9031_202b,
+            0x3fe1_ffa3_2a6c_a42d,
+            0x3fed_1895_abc4_b159,
+            0x3fe1_f1de_5913_fb6d,
+        ] {
+            assert_eq!(rng.next_f64_open().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_f64_open_bounds() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for _ in 0..1024 {
+            let x = rng.next_f64_open();
+            assert!(x > 0.0 && x < 1.0);
+        }
+    }
+
+    #[test]
+    fn next_f64_full_precision() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for e in [
+            0x3fed_b909_c818_9015,
+            0x3fee_8c4a_d5e2_58ac,
+            0x3fdf_9865_b01d_98d8,
+            0x3fc6_6868_677c_6298,
+        ] {
+            assert_eq!(rng.next_f64_full_precision().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_f64_full_precision_bounds() {
+        let mut rng = Sfc64::new(u64::default(), u64::default(), u64::default(), None);
+        for _ in 0..1024 {
+            let x = rng.next_f64_full_precision();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn next_f32() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for e in [0x3f7b_52c5, 0x3e62_00ac, 0x3f14_8279, 0x3f6c_4bf1] {
+            assert_eq!(rng.next_f32().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_f32_bounds() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for _ in 0..1024 {
+            let x = rng.next_f32();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+
+    #[test]
+    fn next_f32_open() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for e in [0x3f7b_52c5, 0x3e62_00ac, 0x3f14_8279, 0x3f6c_4bf1] {
+            assert_eq!(rng.next_f32_open().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_f32_open_bounds() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for _ in 0..1024 {
+            let x = rng.next_f32_open();
+            assert!(x > 0.0 && x < 1.0);
+        }
+    }
+
+    #[test]
+    fn next_f32_full_precision() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for e in [0x3e7d_a962, 0x3f4a_413c, 0x3f3e_5854, 0x3e1e_525c] {
+            assert_eq!(rng.next_f32_full_precision().to_bits(), e);
+        }
+    }
+
+    #[test]
+    fn next_f32_full_precision_bounds() {
+        let mut rng = Sfc32::new(u32::default(), u32::default(), u32::default(), None);
+        for _ in 0..1024 {
+            let x = rng.next_f32_full_precision();
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
@@ -0,0 +1,462 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A hasher built on the sfc64 mixing function.
+
+use core::hash::{BuildHasher, Hasher};
+
+use rand_core::RngCore;
+
+use crate::Sfc64;
+
+/// The number of rounds to mix up the state after absorbing each word.
+const ABSORB_ROUNDS: u32 = 3;
+
+/// The number of additional rounds to mix up the state in
+/// [`SfcHasher::finish`].
+const FINISH_ROUNDS: u32 = 8;
+
+/// A hasher built on the sfc64 mixing function.
+///
+/// This is not suitable for cryptographic uses, and does not resist hash
+/// flooding if the key is known. The hash values are covered by the [value
+/// stability guarantee](crate#value-stability) and do not depend on the
+/// platform, so they can be used as non-cryptographic fingerprints.
+///
+/// The hash value is computed as follows:
+///
+/// 1. The state is initialized by [`Sfc64::new`] with the three words of the
+///    key and 18 rounds.
+/// 2. The written bytes are split into 8-byte words in little-endian order.
+///    Each word is combined by XOR with the `a` word of the state, and then the
+///    state is mixed up 3 rounds. Integers are written as little-endian bytes,
+///    and [`usize`] and [`isize`] are written as [`u64`] and [`i64`].
+/// 3. The remaining bytes, padded with zeros, and then the total number of
+///    written bytes are absorbed in the same way.
+/// 4. The state is mixed up 8 more rounds, and the next output is the hash
+///    value.
+///
+/// # Examples
+///
+/// ```
+/// # use core::hash::Hasher;
+/// #
+/// # use sfc_prng::SfcHasher;
+/// #
+/// let mut hasher = SfcHasher::default();
+/// hasher.write(b"Hello, world!");
+/// assert_eq!(hasher.finish(), 0xe4b2_6dfd_459b_2672);
+/// ```
+#[derive(Clone, Debug)]
+pub struct SfcHasher {
+    rng: Sfc64,
+    tail: u64,
+    tail_len: usize,
+    len: u64,
+}
+
+impl SfcHasher {
+    /// Creates a new `SfcHasher` using the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::hash::Hasher;
+    /// #
+    /// # use sfc_prng::SfcHasher;
+    /// #
+    /// let mut hasher = SfcHasher::new([1, 2, 3]);
+    /// hasher.write_u64(42);
+    /// assert_eq!(hasher.finish(), 0x249e_91c8_2a80_959d);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new([k0, k1, k2]: [u64; 3]) -> Self {
+        Self::from_sfc64(Sfc64::new(k0, k1, k2, None))
+    }
+
+    /// Creates a new `SfcHasher` with the given initial state.
+    pub(crate) const fn from_sfc64(rng: Sfc64) -> Self {
+        Self {
+            rng,
+            tail: 0,
+            tail_len: 0,
+            len: 0,
+        }
+    }
+
+    /// Absorbs the pending bytes and the length, and returns the finalized
+    /// state.
+    pub(crate) fn finalize(&self) -> Sfc64 {
+        let mut rng = self.rng.clone();
+        rng.absorb(self.tail, ABSORB_ROUNDS);
+        rng.absorb(self.len, ABSORB_ROUNDS + FINISH_ROUNDS);
+        rng
+    }
+}
+
+impl Default for SfcHasher {
+    /// Creates a new `SfcHasher` with the key `[0, 0, 0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::SfcHasher;
+    /// #
+    /// let _ = SfcHasher::default();
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl Hasher for SfcHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.finalize().next_u64()
+    }
+
+    #[inline]
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.len = self.len.wrapping_add(bytes.len() as u64);
+        if self.tail_len != 0 {
+            let (head, rest) = bytes.split_at(bytes.len().min(8 - self.tail_len));
+            for b in head {
+                self.tail |= u64::from(*b) << (8 * self.tail_len);
+                self.tail_len += 1;
+            }
+            if self.tail_len < 8 {
+                return;
+            }
+            self.rng.absorb(self.tail, ABSORB_ROUNDS);
+            (self.tail, self.tail_len) = (0, 0);
+            bytes = rest;
+        }
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let word = u64::from_le_bytes(chunk.try_into().unwrap());
+            self.rng.absorb(word, ABSORB_ROUNDS);
+        }
+        for b in chunks.remainder() {
+            self.tail |= u64::from(*b) << (8 * self.tail_len);
+            self.tail_len += 1;
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.write(&[i]);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        if self.tail_len == 0 {
+            self.len = self.len.wrapping_add(8);
+            self.rng.absorb(i, ABSORB_ROUNDS);
+        } else {
+            self.write(&i.to_le_bytes());
+        }
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[allow(clippy::cast_sign_loss)]
+    #[inline]
+    fn write_i64(&mut self, i: i64) {
+        self.write_u64(i as u64);
+    }
+
+    #[inline]
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    #[inline]
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+/// A [`BuildHasher`] which creates [`SfcHasher`]s with the same key.
+///
+/// The state initialized from the key is computed once, so creating an
+/// [`SfcHasher`] with [`build_hasher`](BuildHasher::build_hasher) is cheap.
+///
+/// # Examples
+///
+/// ```
+/// # use std::collections::HashMap;
+/// #
+/// # use sfc_prng::{Sfc64, SfcBuildHasher, rand_core::SeedableRng};
+/// #
+/// let mut rng = Sfc64::from_os_rng();
+/// let mut map = HashMap::with_hasher(SfcBuildHasher::from_rng(&mut rng));
+/// map.insert("a", 1);
+/// assert_eq!(map.get("a"), Some(&1));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SfcBuildHasher(Sfc64);
+
+impl SfcBuildHasher {
+    /// Creates a new `SfcBuildHasher` using the given key.
+    ///
+    /// The hashers created by this are the same as [`SfcHasher::new`] with
+    /// `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::hash::BuildHasher;
+    /// #
+    /// # use sfc_prng::{SfcBuildHasher, SfcHasher};
+    /// #
+    /// let hash_builder = SfcBuildHasher::new([1, 2, 3]);
+    /// assert_eq!(hash_builder.hash_one(42_u64), 0x249e_91c8_2a80_959d);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new([k0, k1, k2]: [u64; 3]) -> Self {
+        Self(Sfc64::new(k0, k1, k2, None))
+    }
+
+    /// Creates a new `SfcBuildHasher` using a random key generated by `rng`.
+    ///
+    /// The key consists of the next three [`u64`] values of `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcBuildHasher};
+    /// #
+    /// let mut rng = Sfc64::new_u64(0, None);
+    /// let _ = SfcBuildHasher::from_rng(&mut rng);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_rng<R: RngCore + ?Sized>(rng: &mut R) -> Self {
+        Self::new([rng.next_u64(), rng.next_u64(), rng.next_u64()])
+    }
+}
+
+impl Default for SfcBuildHasher {
+    /// Creates a new `SfcBuildHasher` with the key `[0, 0, 0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::SfcBuildHasher;
+    /// #
+    /// let _ = SfcBuildHasher::default();
+    /// ```
+    #[inline]
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl BuildHasher for SfcBuildHasher {
+    type Hasher = SfcHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> Self::Hasher {
+        SfcHasher::from_sfc64(self.0.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::hash::Hash;
+
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let hasher = SfcHasher::default();
+        assert_eq!(hasher.clone().finish(), hasher.finish());
+
+        let hash_builder = SfcBuildHasher::default();
+        assert_eq!(hash_builder.clone(), hash_builder);
+    }
+
+    #[test]
+    fn debug() {
+        let mut hasher = SfcHasher::new([1, 2, 3]);
+        hasher.write(&[4, 5]);
+        assert_eq!(
+            format!("{hasher:?}"),
+            "SfcHasher { rng: Sfc64 { a: 811255318603073021, b: 12967139089052859357, c: 10451838264961696528, counter: 19 }, tail: 1284, tail_len: 2, len: 2 }"
+        );
+    }
+
+    #[test]
+    fn finish() {
+        let hasher = SfcHasher::default();
+        assert_eq!(hasher.finish(), 0xcb06_088a_9b16_64a3);
+    }
+
+    #[test]
+    fn finish_is_idempotent() {
+        let mut hasher = SfcHasher::default();
+        hasher.write(b"abc");
+        assert_eq!(hasher.finish(), hasher.finish());
+        hasher.write(b"d");
+        let mut expected = SfcHasher::default();
+        expected.write(b"abcd");
+        assert_eq!(hasher.finish(), expected.finish());
+    }
+
+    #[test]
+    fn write() {
+        for (bytes, expected) in [
+            (&b""[..], 0xcb06_088a_9b16_64a3),
+            (b"a", 0xd963_262a_2c96_1b7b),
+            (b"abcdefgh", 0x3d07_b767_4616_6fcc),
+            (b"Hello, world!", 0xe4b2_6dfd_459b_2672),
+        ] {
+            let mut hasher = SfcHasher::default();
+            hasher.write(bytes);
+            assert_eq!(hasher.finish(), expected);
+        }
+    }
+
+    #[test]
+    fn write_split() {
+        let bytes = b"The quick brown fox jumps over the lazy dog";
+        let mut expected = SfcHasher::new([1, 2, 3]);
+        expected.write(bytes);
+        for mid in 0..=bytes.len() {
+            for end in mid..=bytes.len() {
+                let mut hasher = SfcHasher::new([1, 2, 3]);
+                hasher.write(&bytes[..mid]);
+                hasher.write(&bytes[mid..end]);
+                hasher.write(&bytes[end..]);
+                assert_eq!(hasher.finish(), expected.finish());
+            }
+        }
+    }
+
+    #[test]
+    fn write_length() {
+        let mut hasher = SfcHasher::default();
+        hasher.write(&[0; 3]);
+        let mut other = SfcHasher::default();
+        other.write(&[0; 4]);
+        assert_ne!(hasher.finish(), other.finish());
+    }
+
+    #[test]
+    fn write_integers() {
+        let mut hasher = SfcHasher::default();
+        hasher.write_u8(1);
+        hasher.write_u16(2);
+        hasher.write_u32(3);
+        hasher.write_u64(4);
+        hasher.write_u128(5);
+        hasher.write_usize(6);
+        hasher.write_i8(-1);
+        hasher.write_i16(-2);
+        hasher.write_i32(-3);
+        hasher.write_i64(-4);
+        hasher.write_i128(-5);
+        hasher.write_isize(-6);
+
+        let mut expected = SfcHasher::default();
+        expected.write(&1_u8.to_le_bytes());
+        expected.write(&2_u16.to_le_bytes());
+        expected.write(&3_u32.to_le_bytes());
+        expected.write(&4_u64.to_le_bytes());
+        expected.write(&5_u128.to_le_bytes());
+        expected.write(&6_u64.to_le_bytes());
+        expected.write(&(-1_i8).to_le_bytes());
+        expected.write(&(-2_i16).to_le_bytes());
+        expected.write(&(-3_i32).to_le_bytes());
+        expected.write(&(-4_i64).to_le_bytes());
+        expected.write(&(-5_i128).to_le_bytes());
+        expected.write(&(-6_i64).to_le_bytes());
+        assert_eq!(hasher.finish(), expected.finish());
+        assert_eq!(hasher.finish(), 0x3d4c_5f1f_da5a_dd98);
+    }
+
+    #[test]
+    fn write_u64() {
+        let mut hasher = SfcHasher::default();
+        hasher.write_u64(u64::MAX);
+        assert_eq!(hasher.finish(), 0xa525_5a41_b981_c4d8);
+
+        let mut hasher = SfcHasher::default();
+        hasher.write_u8(u8::default());
+        hasher.write_u64(u64::MAX);
+        let mut expected = SfcHasher::default();
+        expected.write(&[0, 255, 255, 255, 255, 255, 255, 255, 255]);
+        assert_eq!(hasher.finish(), expected.finish());
+    }
+
+    #[test]
+    fn build_hasher() {
+        let hash_builder = SfcBuildHasher::new([1, 2, 3]);
+        let mut hasher = hash_builder.build_hasher();
+        hasher.write(b"Hello, world!");
+        let mut expected = SfcHasher::new([1, 2, 3]);
+        expected.write(b"Hello, world!");
+        assert_eq!(hasher.finish(), expected.finish());
+
+        let mut expected = SfcHasher::new([1, 2, 3]);
+        "Hello, world!".hash(&mut expected);
+        assert_eq!(hash_builder.hash_one("Hello, world!"), expected.finish());
+    }
+
+    #[test]
+    fn build_hasher_default() {
+        let mut expected = SfcHasher::default();
+        expected.write_u64(42);
+        assert_eq!(
+            SfcBuildHasher::default().hash_one(42_u64),
+            expected.finish()
+        );
+    }
+
+    #[test]
+    fn from_rng() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let mut keys = rng.clone();
+        assert_eq!(
+            SfcBuildHasher::from_rng(&mut rng),
+            SfcBuildHasher::new([keys.next_u64(), keys.next_u64(), keys.next_u64()])
+        );
+    }
+}
@@ -0,0 +1,555 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Iterators over random numbers and a reader of random bytes.
+
+use core::iter::FusedIterator;
+#[cfg(feature = "std")]
+use std::io;
+
+use rand_core::RngCore;
+
+use crate::{Sfc32, Sfc64};
+
+/// An endless iterator over random [`u32`] values.
+///
+/// This is created by [`Sfc32::iter_u32`] or [`Sfc64::iter_u32`], and yields
+/// the values of [`next_u32`](RngCore::next_u32).
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{IterU32, Sfc32};
+/// #
+/// let mut iter = IterU32::new(Sfc32::new(0, 0, 0, None));
+/// assert_eq!(iter.next(), Some(0xfb52_c520));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IterU32<R>(R);
+
+impl<R: RngCore> IterU32<R> {
+    /// Creates a new `IterU32` which uses `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{IterU32, Sfc32};
+    /// #
+    /// let _ = IterU32::new(Sfc32::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self(rng)
+    }
+
+    /// Returns the underlying random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{IterU32, Sfc32};
+    /// #
+    /// let iter = IterU32::new(Sfc32::new(0, 0, 0, None));
+    /// assert_eq!(iter.into_inner(), Sfc32::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R: RngCore> Iterator for IterU32<R> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.0.next_u32())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: RngCore> FusedIterator for IterU32<R> {}
+
+/// An endless iterator over random [`u64`] values.
+///
+/// This is created by [`Sfc32::iter_u64`] or [`Sfc64::iter_u64`], and yields
+/// the values of [`next_u64`](RngCore::next_u64).
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{IterU64, Sfc64};
+/// #
+/// let mut iter = IterU64::new(Sfc64::new(0, 0, 0, None));
+/// assert_eq!(iter.next(), Some(0xdb90_9c81_8901_599d));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IterU64<R>(R);
+
+impl<R: RngCore> IterU64<R> {
+    /// Creates a new `IterU64` which uses `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{IterU64, Sfc64};
+    /// #
+    /// let _ = IterU64::new(Sfc64::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self(rng)
+    }
+
+    /// Returns the underlying random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{IterU64, Sfc64};
+    /// #
+    /// let iter = IterU64::new(Sfc64::new(0, 0, 0, None));
+    /// assert_eq!(iter.into_inner(), Sfc64::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0
+    }
+}
+
+impl<R: RngCore> Iterator for IterU64<R> {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.0.next_u64())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: RngCore> FusedIterator for IterU64<R> {}
+
+/// An endless iterator over random bytes.
+///
+/// This is created by [`Sfc32::iter_bytes`] or [`Sfc64::iter_bytes`]. The bytes
+/// are the little-endian bytes of the values of
+/// [`next_u64`](RngCore::next_u64). For [`Sfc32`] and [`Sfc64`], the first `n`
+/// bytes are the same as the bytes written by
+/// [`fill_bytes`](RngCore::fill_bytes) with a destination of length `n`.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{IterBytes, Sfc64};
+/// #
+/// let mut iter = IterBytes::new(Sfc64::new(0, 0, 0, None));
+/// assert_eq!(iter.next(), Some(0x9d));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IterBytes<R> {
+    rng: R,
+    buf: [u8; 8],
+    pos: usize,
+}
+
+impl<R: RngCore> IterBytes<R> {
+    /// Creates a new `IterBytes` which uses `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{IterBytes, Sfc64};
+    /// #
+    /// let _ = IterBytes::new(Sfc64::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng,
+            buf: [0; 8],
+            pos: 8,
+        }
+    }
+
+    /// Returns the underlying random number generator.
+    ///
+    /// The bytes which have been generated but not yet yielded are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{IterBytes, Sfc64};
+    /// #
+    /// let iter = IterBytes::new(Sfc64::new(0, 0, 0, None));
+    /// assert_eq!(iter.into_inner(), Sfc64::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Fills `dst` with the next bytes of the stream.
+    fn fill(&mut self, dst: &mut [u8]) {
+        let (head, rest) = dst.split_at_mut(dst.len().min(8 - self.pos));
+        head.copy_from_slice(&self.buf[self.pos..(self.pos + head.len())]);
+        self.pos += head.len();
+
+        let mut chunks = rest.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.rng.next_u64().to_le_bytes());
+        }
+        let tail = chunks.into_remainder();
+        if !tail.is_empty() {
+            self.buf = self.rng.next_u64().to_le_bytes();
+            tail.copy_from_slice(&self.buf[..tail.len()]);
+            self.pos = tail.len();
+        }
+    }
+}
+
+impl<R: RngCore> Iterator for IterBytes<R> {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut b = [u8::default()];
+        self.fill(&mut b);
+        Some(b[0])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<R: RngCore> FusedIterator for IterBytes<R> {}
+
+/// A reader of an endless stream of random bytes.
+///
+/// The stream is the same as the bytes yielded by [`IterBytes`]. So for
+/// [`Sfc32`] and [`Sfc64`], reading `n` bytes in total, in any number of calls,
+/// gives the same bytes as [`fill_bytes`](RngCore::fill_bytes) with a
+/// destination of length `n`. [`read`](io::Read::read) always fills the whole
+/// buffer and never returns an error.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::Read;
+/// #
+/// # use sfc_prng::{Sfc64, SfcReader, rand_core::RngCore};
+/// #
+/// let mut reader = SfcReader::new(Sfc64::new(0, 0, 0, None));
+/// let mut buf = [u8::default(); 12];
+/// reader.read_exact(&mut buf[..5]).unwrap();
+/// reader.read_exact(&mut buf[5..]).unwrap();
+///
+/// let mut expected = [u8::default(); 12];
+/// Sfc64::new(0, 0, 0, None).fill_bytes(&mut expected);
+/// assert_eq!(buf, expected);
+/// ```
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SfcReader<R>(IterBytes<R>);
+
+#[cfg(feature = "std")]
+impl<R: RngCore> SfcReader<R> {
+    /// Creates a new `SfcReader` which uses `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcReader};
+    /// #
+    /// let _ = SfcReader::new(Sfc64::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self(IterBytes::new(rng))
+    }
+
+    /// Returns the underlying random number generator.
+    ///
+    /// The bytes which have been generated but not yet read are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcReader};
+    /// #
+    /// let reader = SfcReader::new(Sfc64::new(0, 0, 0, None));
+    /// assert_eq!(reader.into_inner(), Sfc64::new(0, 0, 0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.0.into_inner()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: RngCore> io::Read for SfcReader<R> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.fill(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
+        self.0.fill(buf);
+        Ok(())
+    }
+}
+
+impl Sfc32 {
+    /// Returns an endless iterator over random [`u32`] values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// let v = rng.iter_u32().take(2).collect::<Vec<_>>();
+    /// assert_eq!(v, [0xfb52_c520, 0x3880_2be1]);
+    /// ```
+    #[inline]
+    pub const fn iter_u32(&mut self) -> IterU32<&mut Self> {
+        IterU32::new(self)
+    }
+
+    /// Returns an endless iterator over random [`u64`] values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// let v = rng.iter_u64().take(1).collect::<Vec<_>>();
+    /// assert_eq!(v, [0x3880_2be1_fb52_c520]);
+    /// ```
+    #[inline]
+    pub const fn iter_u64(&mut self) -> IterU64<&mut Self> {
+        IterU64::new(self)
+    }
+
+    /// Returns an endless iterator over random bytes.
+    ///
+    /// See [`IterBytes`] for the order of the bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// let v = rng.iter_bytes().take(5).collect::<Vec<_>>();
+    /// assert_eq!(v, [0x20, 0xc5, 0x52, 0xfb, 0xe1]);
+    /// ```
+    #[inline]
+    pub const fn iter_bytes(&mut self) -> IterBytes<&mut Self> {
+        IterBytes::new(self)
+    }
+}
+
+impl Sfc64 {
+    /// Returns an endless iterator over random [`u32`] values.
+    ///
+    /// Like [`Sfc64::next_u32`], each value is the lower 32 bits of a [`u64`]
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// let v = rng.iter_u32().take(2).collect::<Vec<_>>();
+    /// assert_eq!(v, [0x8901_599d, 0x6521_6f57]);
+    /// ```
+    #[inline]
+    pub const fn iter_u32(&mut self) -> IterU32<&mut Self> {
+        IterU32::new(self)
+    }
+
+    /// Returns an endless iterator over random [`u64`] values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// let v = rng.iter_u64().take(2).collect::<Vec<_>>();
+    /// assert_eq!(v, [0xdb90_9c81_8901_599d, 0x8ffd_1953_6521_6f57]);
+    /// ```
+    #[inline]
+    pub const fn iter_u64(&mut self) -> IterU64<&mut Self> {
+        IterU64::new(self)
+    }
+
+    /// Returns an endless iterator over random bytes.
+    ///
+    /// See [`IterBytes`] for the order of the bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// let v = rng.iter_bytes().take(3).collect::<Vec<_>>();
+    /// assert_eq!(v, [0x9d, 0x59, 0x01]);
+    /// ```
+    #[inline]
+    pub const fn iter_bytes(&mut self) -> IterBytes<&mut Self> {
+        IterBytes::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn iter_u32() {
+        {
+            let mut rng = Sfc32::from_seed(Default::default());
+            let mut expected = rng.clone();
+            for x in rng.iter_u32().take(8) {
+                assert_eq!(x, expected.next_u32());
+            }
+            assert_eq!(rng, expected);
+        }
+        {
+            let mut rng = Sfc64::from_seed(Default::default());
+            let mut expected = rng.clone();
+            for x in rng.iter_u32().take(8) {
+                assert_eq!(x, expected.next_u32());
+            }
+            assert_eq!(rng, expected);
+        }
+    }
+
+    #[test]
+    fn iter_u64() {
+        {
+            let mut rng = Sfc32::from_seed(Default::default());
+            let mut expected = rng.clone();
+            for x in rng.iter_u64().take(8) {
+                assert_eq!(x, expected.next_u64());
+            }
+            assert_eq!(rng, expected);
+        }
+        {
+            let mut rng = Sfc64::from_seed(Default::default());
+            let mut expected = rng.clone();
+            for x in rng.iter_u64().take(8) {
+                assert_eq!(x, expected.next_u64());
+            }
+            assert_eq!(rng, expected);
+        }
+    }
+
+    #[test]
+    fn iter_bytes() {
+        for len in [0, 1, 3, 4, 5, 7, 8, 9, 15, 16, 17, 100] {
+            {
+                let mut expected = vec![u8::default(); len];
+                Sfc32::from_seed(Default::default()).fill_bytes(&mut expected);
+                let mut rng = Sfc32::from_seed(Default::default());
+                assert_eq!(rng.iter_bytes().take(len).collect::<Vec<_>>(), expected);
+            }
+            {
+                let mut expected = vec![u8::default(); len];
+                Sfc64::from_seed(Default::default()).fill_bytes(&mut expected);
+                let mut rng = Sfc64::from_seed(Default::default());
+                assert_eq!(rng.iter_bytes().take(len).collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn size_hint() {
+        let mut rng = Sfc64::from_seed(Default::default());
+        assert_eq!(rng.iter_u32().size_hint(), (usize::MAX, None));
+        assert_eq!(rng.iter_u64().size_hint(), (usize::MAX, None));
+        assert_eq!(rng.iter_bytes().size_hint(), (usize::MAX, None));
+        let mut iter = rng.iter_bytes();
+        iter.next();
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn into_inner() {
+        let mut iter = IterBytes::new(Sfc64::from_seed(Default::default()));
+        iter.next();
+        let mut expected = Sfc64::from_seed(Default::default());
+        expected.next_u64();
+        assert_eq!(iter.into_inner(), expected);
+        assert_eq!(
+            IterU32::new(Sfc64::from_seed(Default::default())).into_inner(),
+            Sfc64::from_seed(Default::default())
+        );
+        assert_eq!(
+            IterU64::new(Sfc64::from_seed(Default::default())).into_inner(),
+            Sfc64::from_seed(Default::default())
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn reader() {
+        use std::io::Read;
+
+        let mut expected = [u8::default(); 100];
+        Sfc64::from_seed(Default::default()).fill_bytes(&mut expected);
+        for split in [1, 3, 7, 8, 9, 16, 33, 100] {
+            let mut reader = SfcReader::new(Sfc64::from_seed(Default::default()));
+            let mut buf = [u8::default(); 100];
+            for chunk in buf.chunks_mut(split) {
+                assert_eq!(reader.read(chunk).unwrap(), chunk.len());
+            }
+            assert_eq!(buf, expected);
+        }
+        {
+            let mut expected = [u8::default(); 100];
+            Sfc32::from_seed(Default::default()).fill_bytes(&mut expected);
+            let mut reader = SfcReader::new(Sfc32::from_seed(Default::default()));
+            let mut buf = [u8::default(); 100];
+            reader.read_exact(&mut buf[..5]).unwrap();
+            reader.read_exact(&mut buf[5..]).unwrap();
+            assert_eq!(buf, expected);
+        }
+        {
+            let mut reader = SfcReader::new(Sfc64::from_seed(Default::default()));
+            let mut buf = Vec::new();
+            reader.by_ref().take(20).read_to_end(&mut buf).unwrap();
+            assert_eq!(buf, expected[..20]);
+        }
+    }
+}
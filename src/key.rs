@@ -0,0 +1,226 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Seeding the SFC random number generators from arbitrary-length keys.
+
+use core::hash::Hasher;
+
+use rand_core::RngCore;
+
+use crate::{Sfc32, Sfc64, SfcHasher};
+
+/// The key of the [`SfcHasher`] used by [`Sfc64::from_key`].
+const SFC64_DOMAIN: [u64; 3] = [
+    u64::from_le_bytes(*b"sfc-prng"),
+    u64::from_le_bytes(*b"from_key"),
+    64,
+];
+
+/// The key of the [`SfcHasher`] used by [`Sfc32::from_key`].
+const SFC32_DOMAIN: [u64; 3] = [
+    u64::from_le_bytes(*b"sfc-prng"),
+    u64::from_le_bytes(*b"from_key"),
+    32,
+];
+
+/// Absorbs `key` into the state of an [`SfcHasher`] keyed with `domain`, and
+/// returns the finalized state.
+fn absorb_key(domain: [u64; 3], key: &[u8]) -> Sfc64 {
+    let mut hasher = SfcHasher::new(domain);
+    hasher.write(key);
+    hasher.finalize()
+}
+
+impl Sfc64 {
+    /// Creates a new `Sfc64` from a key of any length.
+    ///
+    /// `key` is written to an [`SfcHasher`] created with the key
+    /// `[u64::from_le_bytes(*b"sfc-prng"), u64::from_le_bytes(*b"from_key"),
+    /// 64]`, and the state of the hasher just before it outputs the hash value
+    /// becomes the state of the generator. Therefore, the first output is the
+    /// same as the hash value of `key`.
+    ///
+    /// The result is covered by the [value stability
+    /// guarantee](crate#value-stability) and does not depend on the platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc64::from_key(b"exp-42/run-7");
+    /// assert_eq!(rng.next_u64(), 0xadec_f02e_3ca1_eb58);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_key(key: &[u8]) -> Self {
+        absorb_key(SFC64_DOMAIN, key)
+    }
+
+    /// Creates a new `Sfc64` from a string key.
+    ///
+    /// This is the same as [`Sfc64::from_key`] with the UTF-8 bytes of `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc64;
+    /// #
+    /// assert_eq!(
+    ///     Sfc64::from_str_key("exp-42/run-7"),
+    ///     Sfc64::from_key(b"exp-42/run-7")
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_str_key(key: &str) -> Self {
+        Self::from_key(key.as_bytes())
+    }
+}
+
+impl Sfc32 {
+    /// Creates a new `Sfc32` from a key of any length.
+    ///
+    /// `key` is written to an [`SfcHasher`] created with the key
+    /// `[u64::from_le_bytes(*b"sfc-prng"), u64::from_le_bytes(*b"from_key"),
+    /// 32]`, and the state of the hasher just before it outputs the hash value
+    /// is used as a [`Sfc64`]. Its next two [`u64`] values `x` and `y` are then
+    /// passed to [`Sfc32::new`] as `a = x as u32`, `b = (x >> 32) as u32` and
+    /// `c = y as u32` with 15 rounds.
+    ///
+    /// The result is covered by the [value stability
+    /// guarantee](crate#value-stability) and does not depend on the platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc32, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc32::from_key(b"exp-42/run-7");
+    /// assert_eq!(rng.next_u32(), 0xc989_bbe4);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    #[inline]
+    pub fn from_key(key: &[u8]) -> Self {
+        let mut rng = absorb_key(SFC32_DOMAIN, key);
+        let (x, y) = (rng.next_u64(), rng.next_u64());
+        Self::new(x as u32, (x >> u32::BITS) as u32, y as u32, None)
+    }
+
+    /// Creates a new `Sfc32` from a string key.
+    ///
+    /// This is the same as [`Sfc32::from_key`] with the UTF-8 bytes of `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32;
+    /// #
+    /// assert_eq!(
+    ///     Sfc32::from_str_key("exp-42/run-7"),
+    ///     Sfc32::from_key(b"exp-42/run-7")
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_str_key(key: &str) -> Self {
+        Self::from_key(key.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXPECTED_SFC64_EMPTY: [u64; 4] = [
+        0xb7c2_efa1_b222_1c40,
+        0xa894_d3a5_8ff7_8ef2,
+        0x66d5_b0bc_4a59_6191,
+        0x4d92_9910_35c2_23f3,
+    ];
+    static EXPECTED_SFC64: [u64; 4] = [
+        0xadec_f02e_3ca1_eb58,
+        0x1d7e_a68a_d267_5067,
+        0xed04_03f5_83ea_e92c,
+        0x08e6_fcfc_c863_0b7e,
+    ];
+    static EXPECTED_SFC32_EMPTY: [u32; 4] = [0x01b5_ebfe, 0x7a75_b5e0, 0xb153_0a7a, 0xb9ad_e63f];
+    static EXPECTED_SFC32: [u32; 4] = [0xc989_bbe4, 0x43ea_dd1b, 0x0ea4_05e3, 0xbda0_7baf];
+
+    #[test]
+    fn domain() {
+        assert_eq!(
+            SFC64_DOMAIN,
+            [0x676e_7270_2d63_6673, 0x7965_6b5f_6d6f_7266, 0x40]
+        );
+        assert_eq!(
+            SFC32_DOMAIN,
+            [0x676e_7270_2d63_6673, 0x7965_6b5f_6d6f_7266, 0x20]
+        );
+    }
+
+    #[test]
+    fn sfc64_from_key() {
+        {
+            let mut rng = Sfc64::from_key(b"");
+            for e in EXPECTED_SFC64_EMPTY {
+                assert_eq!(rng.next_u64(), e);
+            }
+        }
+        {
+            let mut rng = Sfc64::from_key(b"exp-42/run-7");
+            for e in EXPECTED_SFC64 {
+                assert_eq!(rng.next_u64(), e);
+            }
+        }
+    }
+
+    #[test]
+    fn sfc64_from_key_is_hash() {
+        let mut hasher = SfcHasher::new(SFC64_DOMAIN);
+        hasher.write(b"exp-42/run-7");
+        assert_eq!(Sfc64::from_key(b"exp-42/run-7").next_u64(), hasher.finish());
+    }
+
+    #[test]
+    fn sfc64_from_str_key() {
+        let mut rng = Sfc64::from_str_key("exp-42/run-7");
+        for e in EXPECTED_SFC64 {
+            assert_eq!(rng.next_u64(), e);
+        }
+        assert_ne!(
+            Sfc64::from_str_key("exp-42/run-7"),
+            Sfc64::from_str_key("exp-42/run-8")
+        );
+    }
+
+    #[test]
+    fn sfc32_from_key() {
+        {
+            let mut rng = Sfc32::from_key(b"");
+            for e in EXPECTED_SFC32_EMPTY {
+                assert_eq!(rng.next_u32(), e);
+            }
+        }
+        {
+            let mut rng = Sfc32::from_key(b"exp-42/run-7");
+            for e in EXPECTED_SFC32 {
+                assert_eq!(rng.next_u32(), e);
+            }
+        }
+    }
+
+    #[test]
+    fn sfc32_from_str_key() {
+        let mut rng = Sfc32::from_str_key("exp-42/run-7");
+        for e in EXPECTED_SFC32 {
+            assert_eq!(rng.next_u32(), e);
+        }
+        assert_ne!(
+            Sfc32::from_str_key("exp-42/run-7"),
+            Sfc32::from_str_key("exp-42/run-8")
+        );
+    }
+}
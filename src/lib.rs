@@ -17,6 +17,53 @@
 //! The sfc32 algorithm is implemented as [`Sfc32`], and the sfc64 algorithm is
 //! implemented as [`Sfc64`].
 //!
+//! [`Sfc32x8`] and [`Sfc64x4`] step several independent streams together, which
+//! allows the compiler to use SIMD instructions.
+//!
+//! [`Sfc32Core`] and [`Sfc64Core`] can be used with the block-based wrappers of
+//! [`rand_core::block`] to generate random numbers in blocks.
+//!
+//! [`Sfc64Split32`] returns both halves of each output of [`Sfc64`] as [`u32`]
+//! values.
+//!
+//! [`SfcHasher`] and [`SfcBuildHasher`] implement
+//! [`Hasher`](core::hash::Hasher) and [`BuildHasher`](core::hash::BuildHasher)
+//! using the sfc64 mixing function.
+//!
+//! [`PeriodGuarded`] returns an error instead of exceeding the guaranteed
+//! minimum period of the generator.
+//!
+//! With the `alloc` feature, [`SfcStreamIndex`] records snapshots of a
+//! [`Sfc64`] stream, so that it can jump to any output index, and
+//! [`Recording`] and [`Replay`] record the calls to a generator and check that
+//! the same calls happen again.
+//!
+//! [`Sfc64::from_key`] and [`Sfc32::from_key`] create a generator from a key of
+//! any length, such as the name of an experiment.
+//!
+//! [`SharedSfc64`] can be used through a shared reference from many threads
+//! without a [`Mutex`](https://doc.rust-lang.org/std/sync/struct.Mutex.html).
+//! It shards its state and protects each shard with a spin lock.
+//!
+//! With the `rayon` feature, [`par_fill_bytes`] and [`par_fill_u64`] fill large
+//! buffers in parallel with a result which does not depend on the number of
+//! threads.
+//!
+//! With the `analysis` feature, the [`analysis`] module provides a variant of
+//! the SFC algorithm with 8-bit words, whose cycle structure can be found
+//! exhaustively to check the documented periods, and measures the avalanche of
+//! the seed mixing of the constructors.
+//!
+//! With the `quality` feature, the [`quality`] module provides quick
+//! statistical tests for any random number generator, and
+//! [`quality::Interleaved`] runs them on the interleaved outputs of several
+//! generators to detect correlations between their streams.
+//!
+//! With the `std` feature, [`thread_rng`] and [`with_thread_rng`] provide a
+//! lazily initialized generator for each thread, and [`SfcReader`] implements
+//! [`Read`](https://doc.rust-lang.org/std/io/trait.Read.html) as an endless
+//! stream of random bytes.
+//!
 //! This crate supports version 4 of the SFC algorithms.
 //!
 //! # Examples
@@ -32,6 +79,14 @@
 //! assert_eq!(x, 0xd396_d4b3_98b6_c85d);
 //! ```
 //!
+//! # Value stability
+//!
+//! The values produced by the random number generators for a given seed never
+//! change, except in a semver-major release. This also applies to the methods
+//! which convert them to other types, such as [`Sfc64::next_f64`] and
+//! [`Sfc64::gen_below`], to the algorithms in the [`seq`] module, and to the
+//! hash values of [`SfcHasher`].
+//!
 //! [Chris Doty-Humphrey's Small Fast Counting PRNGs]: https://pracrand.sourceforge.net/RNG_engines.txt
 
 #![doc(html_root_url = "https://docs.rs/sfc-prng/0.3.0/")]
@@ -40,13 +95,68 @@
 // Lint levels of rustc.
 #![deny(missing_docs)]
 
-#[cfg(test)]
-#[macro_use]
+#[cfg(any(feature = "alloc", test))]
+#[cfg_attr(test, macro_use)]
 extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
 
+mod block;
+mod bounded;
+mod float;
+mod hash;
+mod iter;
+mod key;
+#[cfg(feature = "rayon")]
+mod par;
+mod period;
+#[cfg(feature = "alloc")]
+mod record;
 mod sfc32;
+mod sfc32x8;
 mod sfc64;
+mod sfc64_split32;
+mod sfc64x4;
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+mod shared;
+#[cfg(feature = "alloc")]
+mod stream;
+#[cfg(feature = "std")]
+mod thread;
+
+#[cfg(feature = "analysis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "analysis")))]
+pub mod analysis;
+#[cfg(feature = "quality")]
+#[cfg_attr(docsrs, doc(cfg(feature = "quality")))]
+pub mod quality;
+pub mod seq;
 
 pub use rand_core;
 
-pub use crate::{sfc32::Sfc32, sfc64::Sfc64};
+#[cfg(feature = "std")]
+pub use crate::iter::SfcReader;
+#[cfg(feature = "rayon")]
+pub use crate::par::{PAR_CHUNK_LEN, par_fill_bytes, par_fill_u64};
+#[cfg(feature = "alloc")]
+pub use crate::record::{Call, CallKind, Recording, Replay, ReplayError};
+#[cfg(any(target_has_atomic = "64", feature = "portable-atomic"))]
+pub use crate::shared::SharedSfc64;
+#[cfg(feature = "alloc")]
+pub use crate::stream::SfcStreamIndex;
+#[cfg(feature = "std")]
+pub use crate::thread::{
+    SEED_ENV_VAR, ThreadRng, reseed_thread_rng, seed_thread_rng, thread_rng, with_thread_rng,
+};
+pub use crate::{
+    block::{Sfc32Core, Sfc64Core},
+    bounded::BoundedInt,
+    hash::{SfcBuildHasher, SfcHasher},
+    iter::{IterBytes, IterU32, IterU64},
+    period::{MinimumPeriod, PeriodExceededError, PeriodGuarded},
+    sfc32::Sfc32,
+    sfc32x8::Sfc32x8,
+    sfc64::Sfc64,
+    sfc64_split32::Sfc64Split32,
+    sfc64x4::Sfc64x4,
+};
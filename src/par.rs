@@ -0,0 +1,179 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Parallel generation of random numbers using [Rayon].
+//!
+//! [Rayon]: https://crates.io/crates/rayon
+
+use rand_core::RngCore;
+use rayon::{
+    iter::{IndexedParallelIterator, ParallelIterator},
+    slice::ParallelSliceMut,
+};
+
+use crate::Sfc64;
+
+/// The length of each chunk, in bytes, used by [`par_fill_bytes`] and
+/// [`par_fill_u64`].
+///
+/// This is part of the [value stability guarantee](crate#value-stability).
+pub const PAR_CHUNK_LEN: usize = 1 << 16;
+
+/// Returns the generator used for the chunk at `index`.
+fn chunk_rng(seed: u64, index: usize) -> Sfc64 {
+    Sfc64::new(seed, index as u64, 0, None)
+}
+
+/// Fills `dst` with random data in parallel.
+///
+/// `dst` is split into chunks of [`PAR_CHUNK_LEN`] bytes, and the last chunk
+/// may be shorter. The chunk at index `i` (counting from 0) is filled by
+/// [`Sfc64::fill_bytes`](RngCore::fill_bytes) of a new generator created with
+/// `Sfc64::new(seed, i as u64, 0, None)`.
+///
+/// Therefore, the result depends only on `seed` and [`PAR_CHUNK_LEN`], not on
+/// the number of threads, and it can also be reproduced on a single thread.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{PAR_CHUNK_LEN, Sfc64, par_fill_bytes, rand_core::RngCore};
+/// #
+/// let mut buf = vec![u8::default(); PAR_CHUNK_LEN * 2 + 8];
+/// par_fill_bytes(0, &mut buf);
+///
+/// let mut expected = vec![u8::default(); buf.len()];
+/// for (i, chunk) in expected.chunks_mut(PAR_CHUNK_LEN).enumerate() {
+///     Sfc64::new(0, i as u64, 0, None).fill_bytes(chunk);
+/// }
+/// assert_eq!(buf, expected);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[inline]
+pub fn par_fill_bytes(seed: u64, dst: &mut [u8]) {
+    dst.par_chunks_mut(PAR_CHUNK_LEN)
+        .enumerate()
+        .for_each(|(i, chunk)| chunk_rng(seed, i).fill_bytes(chunk));
+}
+
+/// Fills `dst` with random [`u64`] values in parallel.
+///
+/// `dst` is split into chunks of [`PAR_CHUNK_LEN`] bytes (that is,
+/// `PAR_CHUNK_LEN / 8` values), and each chunk is filled by a generator derived
+/// in the same way as [`par_fill_bytes`]. As a result, the values are the same
+/// as the little-endian interpretation of the bytes written by
+/// [`par_fill_bytes`] with the same `seed`.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, par_fill_bytes, par_fill_u64, rand_core::RngCore};
+/// #
+/// let mut buf = [u64::default(); 4];
+/// par_fill_u64(0, &mut buf);
+/// let mut rng = Sfc64::new(0, 0, 0, None);
+/// assert_eq!(buf, [(); 4].map(|()| rng.next_u64()));
+///
+/// let mut bytes = [u8::default(); 32];
+/// par_fill_bytes(0, &mut bytes);
+/// assert_eq!(buf[0].to_le_bytes(), bytes[..8]);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+#[inline]
+pub fn par_fill_u64(seed: u64, dst: &mut [u64]) {
+    dst.par_chunks_mut(PAR_CHUNK_LEN / size_of::<u64>())
+        .enumerate()
+        .for_each(|(i, chunk)| chunk_rng(seed, i).fill_u64(chunk));
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use rayon::ThreadPoolBuilder;
+
+    use super::*;
+
+    const LEN: usize = PAR_CHUNK_LEN * 3 + 13;
+
+    fn fill_bytes_sequential(seed: u64, dst: &mut [u8]) {
+        for (i, chunk) in dst.chunks_mut(PAR_CHUNK_LEN).enumerate() {
+            chunk_rng(seed, i).fill_bytes(chunk);
+        }
+    }
+
+    #[test]
+    fn chunk_len() {
+        assert_eq!(PAR_CHUNK_LEN, 65536);
+        assert_eq!(PAR_CHUNK_LEN % size_of::<u64>(), 0);
+    }
+
+    #[test]
+    fn chunk_seed() {
+        assert_eq!(chunk_rng(1, 2), Sfc64::new(1, 2, 0, None));
+    }
+
+    #[test]
+    fn par_fill_bytes() {
+        let mut expected = vec![u8::default(); LEN];
+        fill_bytes_sequential(1, &mut expected);
+
+        let mut dst = vec![u8::default(); LEN];
+        super::par_fill_bytes(1, &mut dst);
+        assert_eq!(dst, expected);
+
+        let mut first = [u8::default(); 8];
+        Sfc64::new(1, 0, 0, None).fill_bytes(&mut first);
+        assert_eq!(dst[..8], first);
+    }
+
+    #[test]
+    fn par_fill_bytes_thread_count() {
+        let mut expected = vec![u8::default(); LEN];
+        fill_bytes_sequential(u64::MAX, &mut expected);
+        for num_threads in [1, 2, 3, 8] {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let mut dst = vec![u8::default(); LEN];
+            pool.install(|| super::par_fill_bytes(u64::MAX, &mut dst));
+            assert_eq!(dst, expected);
+        }
+    }
+
+    #[test]
+    fn par_fill_bytes_empty() {
+        super::par_fill_bytes(u64::default(), &mut []);
+    }
+
+    #[test]
+    fn par_fill_u64() {
+        let mut bytes = vec![u8::default(); LEN];
+        super::par_fill_bytes(1, &mut bytes);
+        let expected = bytes
+            .chunks_exact(size_of::<u64>())
+            .map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+            .collect::<Vec<_>>();
+
+        let mut dst = vec![u64::default(); LEN / size_of::<u64>()];
+        super::par_fill_u64(1, &mut dst);
+        assert_eq!(dst, expected);
+    }
+
+    #[test]
+    fn par_fill_u64_thread_count() {
+        let mut expected = vec![u64::default(); LEN / size_of::<u64>()];
+        super::par_fill_u64(u64::MAX, &mut expected);
+        for num_threads in [1, 2, 3, 8] {
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap();
+            let mut dst = vec![u64::default(); expected.len()];
+            pool.install(|| super::par_fill_u64(u64::MAX, &mut dst));
+            assert_eq!(dst, expected);
+        }
+    }
+}
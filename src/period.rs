@@ -0,0 +1,361 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Counting outputs and guarding the minimum period.
+
+use core::{error::Error, fmt};
+
+use rand_core::{RngCore, TryRngCore};
+
+use crate::{Sfc32, Sfc64};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Sfc32 {}
+    impl Sealed for super::Sfc64 {}
+}
+
+/// A random number generator with a guaranteed minimum period, which can be
+/// used with [`PeriodGuarded`].
+///
+/// This trait is sealed and implemented for [`Sfc32`] and [`Sfc64`]. The
+/// minimum period of [`Sfc32`] is 2<sup>32</sup> [`u32`] values, and that of
+/// [`Sfc64`] is 2<sup>64</sup> [`u64`] values.
+pub trait MinimumPeriod: RngCore + private::Sealed {
+    /// The type of the number of words counted by [`PeriodGuarded`], which is
+    /// [`u64`] for [`Sfc32`] and [`u128`] for [`Sfc64`], so that it can count
+    /// a whole minimum period.
+    type Count: Copy + fmt::Debug + Eq + Ord;
+
+    #[doc(hidden)]
+    const WORD_BYTES: u64;
+
+    #[doc(hidden)]
+    const MIN_PERIOD_LOG2: u32;
+
+    #[doc(hidden)]
+    fn counter(&self) -> u128;
+
+    #[doc(hidden)]
+    fn to_count(count: u128) -> Self::Count;
+}
+
+impl MinimumPeriod for Sfc32 {
+    type Count = u64;
+
+    const WORD_BYTES: u64 = 4;
+    const MIN_PERIOD_LOG2: u32 = 32;
+
+    #[inline]
+    fn counter(&self) -> u128 {
+        self.state()[3].into()
+    }
+
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn to_count(count: u128) -> Self::Count {
+        count as u64
+    }
+}
+
+impl MinimumPeriod for Sfc64 {
+    type Count = u128;
+
+    const WORD_BYTES: u64 = 8;
+    const MIN_PERIOD_LOG2: u32 = 64;
+
+    #[inline]
+    fn counter(&self) -> u128 {
+        self.state()[3].into()
+    }
+
+    #[inline]
+    fn to_count(count: u128) -> Self::Count {
+        count
+    }
+}
+
+/// The error returned by [`PeriodGuarded`] when a request would exceed the
+/// guaranteed minimum period.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::PeriodExceededError;
+/// #
+/// assert_eq!(
+///     PeriodExceededError.to_string(),
+///     "the guaranteed minimum period would be exceeded"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PeriodExceededError;
+
+impl fmt::Display for PeriodExceededError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the guaranteed minimum period would be exceeded")
+    }
+}
+
+impl Error for PeriodExceededError {}
+
+/// A wrapper which stops a random number generator before it exceeds its
+/// guaranteed minimum period.
+///
+/// This counts the words generated by the underlying generator since the
+/// wrapper was created. The count is derived from the counter word of the
+/// generator plus the number of times the counter has wrapped around, so it
+/// does not wrap around at the minimum period. A request which would make the
+/// count exceed the minimum period of the generator returns
+/// [`PeriodExceededError`] without using the generator. So the values produced
+/// by this are never part of a repeated cycle.
+///
+/// The numbers of words used by each method are as follows:
+///
+/// - [`try_next_u32`](TryRngCore::try_next_u32) uses one word.
+/// - [`try_next_u64`](TryRngCore::try_next_u64) uses two words for [`Sfc32`]
+///   and one word for [`Sfc64`].
+/// - [`try_fill_bytes`](TryRngCore::try_fill_bytes) uses `dst.len() / 4` words
+///   rounded up for [`Sfc32`], and `dst.len() / 8` words rounded up for
+///   [`Sfc64`].
+///
+/// Since [`RngCore`] cannot return an error, this only implements
+/// [`TryRngCore`]. Use [`UnwrapErr`](rand_core::UnwrapErr) to panic instead.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{
+/// #     PeriodGuarded, Sfc32,
+/// #     rand_core::{SeedableRng, TryRngCore},
+/// # };
+/// #
+/// let mut rng = PeriodGuarded::new(Sfc32::from_seed([0; 12]));
+/// assert_eq!(rng.try_next_u32(), Ok(0xfb52_c520));
+/// assert_eq!(rng.outputs_generated(), 1);
+/// assert_eq!(rng.remaining(), (1 << 32) - 1);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PeriodGuarded<R> {
+    rng: R,
+    start: u128,
+    last: u128,
+    wraps: u128,
+}
+
+impl<R: MinimumPeriod> PeriodGuarded<R> {
+    /// Creates a new `PeriodGuarded` which wraps `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{PeriodGuarded, Sfc32};
+    /// #
+    /// let _ = PeriodGuarded::new(Sfc32::new_u64(0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(rng: R) -> Self {
+        let start = rng.counter();
+        Self {
+            rng,
+            start,
+            last: start,
+            wraps: 0,
+        }
+    }
+
+    /// Returns the number of words generated since this was created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{PeriodGuarded, Sfc32, rand_core::TryRngCore};
+    /// #
+    /// let mut rng = PeriodGuarded::new(Sfc32::new_u64(0, None));
+    /// rng.try_next_u64().unwrap();
+    /// assert_eq!(rng.outputs_generated(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn outputs_generated(&self) -> R::Count {
+        R::to_count(self.generated())
+    }
+
+    /// Returns the number of words which can still be generated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{PeriodGuarded, Sfc32, rand_core::TryRngCore};
+    /// #
+    /// let mut rng = PeriodGuarded::new(Sfc32::new_u64(0, None));
+    /// rng.try_next_u32().unwrap();
+    /// assert_eq!(rng.remaining(), (1 << 32) - 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn remaining(&self) -> R::Count {
+        R::to_count(self.remaining_words())
+    }
+
+    /// Returns the underlying random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{PeriodGuarded, Sfc32};
+    /// #
+    /// let rng = PeriodGuarded::new(Sfc32::new_u64(0, None));
+    /// assert_eq!(rng.into_inner(), Sfc32::new_u64(0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.rng
+    }
+
+    /// Returns the number of words generated since this was created.
+    const fn generated(&self) -> u128 {
+        (self.wraps << R::MIN_PERIOD_LOG2) + self.last - self.start
+    }
+
+    /// Returns the number of words which can still be generated.
+    const fn remaining_words(&self) -> u128 {
+        (1 << R::MIN_PERIOD_LOG2) - self.generated()
+    }
+
+    /// Calls `f` with the generator if `words` words do not exceed the minimum
+    /// period, and then counts the wraps of the counter.
+    fn generate<T>(
+        &mut self,
+        words: u128,
+        f: impl FnOnce(&mut R) -> T,
+    ) -> Result<T, PeriodExceededError> {
+        if words > self.remaining_words() {
+            return Err(PeriodExceededError);
+        }
+        let value = f(&mut self.rng);
+        let counter = self.rng.counter();
+        // At most a whole minimum period is generated at a time, so the counter
+        // wraps around at most once. If it does, it is either smaller than
+        // before, or the same if exactly a whole minimum period is generated.
+        if counter < self.last || (words != 0 && counter == self.last) {
+            self.wraps += 1;
+        }
+        self.last = counter;
+        Ok(value)
+    }
+}
+
+impl<R: MinimumPeriod> TryRngCore for PeriodGuarded<R> {
+    type Error = PeriodExceededError;
+
+    #[inline]
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.generate(1, RngCore::next_u32)
+    }
+
+    #[inline]
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.generate(u128::from(8 / R::WORD_BYTES), RngCore::next_u64)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        let words = u128::from((dst.len() as u64).div_ceil(R::WORD_BYTES));
+        self.generate(words, |rng| rng.fill_bytes(dst))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn outputs_generated_across_wrap() {
+        let mut rng = PeriodGuarded::new(Sfc32::from_state([1, 2, 3, u32::MAX - 1]));
+        let mut expected = Sfc32::from_state([1, 2, 3, u32::MAX - 1]);
+        for i in 1..=4 {
+            assert_eq!(rng.try_next_u32(), Ok(expected.next_u32()));
+            assert_eq!(rng.outputs_generated(), i);
+        }
+        assert!(rng.try_fill_bytes(&mut [u8::default(); 9]).is_ok());
+        assert_eq!(rng.outputs_generated(), 7);
+        assert_eq!(rng.remaining(), (1 << 32) - 7);
+
+        let mut rng = PeriodGuarded::new(Sfc64::from_state([1, 2, 3, u64::MAX]));
+        assert!(rng.try_next_u64().is_ok());
+        assert!(rng.try_fill_bytes(&mut [u8::default(); 16]).is_ok());
+        assert_eq!(rng.outputs_generated(), 3);
+        assert_eq!(rng.remaining(), (1 << 64) - 3);
+    }
+
+    #[test]
+    fn period_guarded() {
+        let mut rng = PeriodGuarded::new(Sfc32::from_seed(Default::default()));
+        let mut expected = Sfc32::from_seed(Default::default());
+        assert_eq!(rng.try_next_u32(), Ok(expected.next_u32()));
+        assert_eq!(rng.try_next_u64(), Ok(expected.next_u64()));
+        let (mut dst, mut expected_dst) = ([u8::default(); 9], [u8::default(); 9]);
+        assert_eq!(rng.try_fill_bytes(&mut dst), Ok(()));
+        expected.fill_bytes(&mut expected_dst);
+        assert_eq!(dst, expected_dst);
+        assert_eq!(rng.outputs_generated(), 6);
+        assert_eq!(rng.into_inner(), expected);
+    }
+
+    #[test]
+    fn period_guarded_sfc64() {
+        let mut rng = PeriodGuarded::new(Sfc64::from_seed(Default::default()));
+        assert!(rng.try_next_u32().is_ok());
+        assert!(rng.try_next_u64().is_ok());
+        assert!(rng.try_fill_bytes(&mut [u8::default(); 9]).is_ok());
+        assert_eq!(rng.outputs_generated(), 4);
+        assert_eq!(rng.remaining(), (1 << 64) - 4);
+    }
+
+    #[test]
+    fn period_guarded_exceeded() {
+        let mut rng = PeriodGuarded::new(Sfc32::from_seed(Default::default()));
+        (rng.start, rng.wraps) = (rng.last + 3, 1);
+        assert_eq!(rng.outputs_generated(), (1 << 32) - 3);
+        let expected = rng.clone();
+        assert_eq!(
+            rng.try_fill_bytes(&mut [u8::default(); 13]),
+            Err(PeriodExceededError)
+        );
+        assert_eq!(rng, expected);
+        assert!(rng.try_next_u64().is_ok());
+        assert_eq!(rng.remaining(), 1);
+        assert_eq!(rng.try_next_u64(), Err(PeriodExceededError));
+        assert!(rng.try_next_u32().is_ok());
+        assert_eq!(rng.remaining(), 0);
+        assert_eq!(rng.try_next_u32(), Err(PeriodExceededError));
+        assert_eq!(rng.try_fill_bytes(&mut []), Ok(()));
+        assert_eq!(
+            rng.try_fill_bytes(&mut [u8::default()]),
+            Err(PeriodExceededError)
+        );
+    }
+
+    #[test]
+    fn unwrap_err() {
+        let mut rng = PeriodGuarded::new(Sfc32::from_seed(Default::default())).unwrap_err();
+        let mut expected = Sfc32::from_seed(Default::default());
+        assert_eq!(rng.next_u32(), expected.next_u32());
+    }
+
+    #[test]
+    #[should_panic(expected = "PeriodExceededError")]
+    fn unwrap_err_exceeded() {
+        let mut rng = PeriodGuarded::new(Sfc32::from_seed(Default::default()));
+        rng.wraps = 1;
+        rng.unwrap_err().next_u32();
+    }
+}
@@ -0,0 +1,825 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Quick statistical tests for random number generators.
+//!
+//! These tests take a fraction of a second, so they are useful as a smoke check
+//! before a long run of a test suite such as
+//! [PractRand](https://pracrand.sourceforge.net/) or
+//! [TestU01](https://simul.iro.umontreal.ca/testu01/tu01.html). They catch a
+//! grossly broken generator, such as one with a wrong step function or wrong
+//! constants, but passing them does not mean that a generator is good. The SFC
+//! algorithms are robust enough that changing a single shift or rotation
+//! constant still passes these tests on a single stream. Some such changes make
+//! the seed mixing slower, which [`Interleaved`] generators with neighbouring
+//! seeds and few mixing rounds can detect. For example, sfc64 with the rotation
+//! constant of sfc32 fails after 4 rounds. Still, the exact test vectors remain
+//! the primary check of the implementation.
+//!
+//! Each test returns a [`TestResult`] with a p-value. For a good generator,
+//! the p-value is uniformly distributed in the interval `[0, 1]`, so a p-value
+//! very close to 0 indicates a failure. All tests work with any [`RngCore`].
+//!
+//! # Examples
+//!
+//! ```
+//! use sfc_prng::{Sfc64, quality};
+//!
+//! let mut rng = Sfc64::new_u64(0, None);
+//! for result in quality::quick_check(&mut rng) {
+//!     assert!(result.p_value() > 1e-6, "{result}");
+//! }
+//! ```
+
+use alloc::vec::Vec;
+use core::{f64::consts::PI, fmt};
+
+use rand_core::RngCore;
+
+/// The result of a statistical test.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// let result = quality::monobit(&mut rng, 1024);
+/// assert_eq!(result.name(), "monobit");
+/// assert!((0.0..=1.0).contains(&result.p_value()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TestResult {
+    name: &'static str,
+    statistic: f64,
+    p_value: f64,
+}
+
+impl TestResult {
+    const fn new(name: &'static str, statistic: f64, p_value: f64) -> Self {
+        Self {
+            name,
+            statistic,
+            p_value: p_value.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Returns the name of the test.
+    #[must_use]
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the test statistic.
+    ///
+    /// The meaning of the statistic depends on the test, and is described in
+    /// the documentation of each test.
+    #[must_use]
+    #[inline]
+    pub const fn statistic(&self) -> f64 {
+        self.statistic
+    }
+
+    /// Returns the p-value, which is the probability that a truly random
+    /// sequence gives a result at least as extreme as this one.
+    #[must_use]
+    #[inline]
+    pub const fn p_value(&self) -> f64 {
+        self.p_value
+    }
+}
+
+impl fmt::Display for TestResult {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: statistic = {}, p-value = {}",
+            self.name, self.statistic, self.p_value
+        )
+    }
+}
+
+/// Runs all tests in this module with the default sizes, and returns their
+/// results.
+///
+/// This uses about 10 MiB of random data.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc32, quality};
+/// #
+/// let mut rng = Sfc32::new_u64(0, None);
+/// let results = quality::quick_check(&mut rng);
+/// assert!(results.iter().all(|r| r.p_value() > 1e-6));
+/// ```
+#[inline]
+pub fn quick_check<R: RngCore + ?Sized>(rng: &mut R) -> [TestResult; 6] {
+    [
+        monobit(rng, 1 << 16),
+        byte_frequency(rng, 1 << 20),
+        runs(rng, 1 << 16),
+        gap(rng, 1 << 16),
+        birthday_spacings(rng, 64),
+        linear_complexity(rng, 500),
+    ]
+}
+
+/// The frequency (monobit) test.
+///
+/// This counts the ones in the bits of `words` values of
+/// [`RngCore::next_u64`]. The statistic is `|ones - zeros| / sqrt(n)`, where
+/// `n` is the number of bits, which approximately follows the half-normal
+/// distribution.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert!(quality::monobit(&mut rng, 1 << 12).p_value() > 1e-6);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn monobit<R: RngCore + ?Sized>(rng: &mut R, words: usize) -> TestResult {
+    let ones = (0..words)
+        .map(|_| u64::from(rng.next_u64().count_ones()))
+        .sum::<u64>();
+    let n = words as f64 * 64.0;
+    let statistic = (2.0f64.mul_add(ones as f64, -n)).abs() / n.sqrt();
+    TestResult::new("monobit", statistic, erfc(statistic / 2.0f64.sqrt()))
+}
+
+/// The chi-square test of the frequencies of byte values.
+///
+/// This counts each byte value in `bytes` bytes of [`RngCore::fill_bytes`].
+/// The statistic is the chi-square statistic with 255 degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert!(quality::byte_frequency(&mut rng, 1 << 16).p_value() > 1e-6);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn byte_frequency<R: RngCore + ?Sized>(rng: &mut R, bytes: usize) -> TestResult {
+    let mut counts = [u64::default(); 256];
+    let mut buf = [u8::default(); 4096];
+    let mut remaining = bytes;
+    while remaining != 0 {
+        let chunk = &mut buf[..remaining.min(4096)];
+        rng.fill_bytes(chunk);
+        for &b in &*chunk {
+            counts[usize::from(b)] += 1;
+        }
+        remaining -= chunk.len();
+    }
+    let expected = probabilities::<256>(|_| 1.0 / 256.0);
+    let statistic = chi_square(&counts, &expected);
+    TestResult::new(
+        "byte frequency",
+        statistic,
+        chi_square_p_value(statistic, 255),
+    )
+}
+
+/// The runs test.
+///
+/// This counts the runs of identical bits in the bits of `words` values of
+/// [`RngCore::next_u64`]. The statistic is the number of runs normalized to
+/// the standard normal distribution, and its absolute value is tested. If the
+/// proportion of ones is too far from 1/2 for this test to be meaningful, the
+/// p-value is 0.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert!(quality::runs(&mut rng, 1 << 12).p_value() > 1e-6);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn runs<R: RngCore + ?Sized>(rng: &mut R, words: usize) -> TestResult {
+    let (mut ones, mut transitions) = (u64::default(), u64::default());
+    let mut last_bit = None;
+    for _ in 0..words {
+        let x = rng.next_u64();
+        ones += u64::from(x.count_ones());
+        transitions += u64::from(((x ^ (x >> 1)) & (u64::MAX >> 1)).count_ones());
+        if let Some(bit) = last_bit {
+            transitions += (bit ^ x) & 1;
+        }
+        last_bit = Some(x >> 63);
+    }
+    let n = words as f64 * 64.0;
+    let pi = ones as f64 / n;
+    if (pi - 0.5).abs() >= 2.0 / n.sqrt() {
+        return TestResult::new("runs", f64::INFINITY, 0.0);
+    }
+    let runs = (transitions + 1) as f64;
+    let expected = 2.0 * n * pi * (1.0 - pi);
+    let statistic = (runs - expected) / (2.0 * (2.0 * n).sqrt() * pi * (1.0 - pi));
+    TestResult::new("runs", statistic, erfc(statistic.abs()))
+}
+
+/// The number of gap lengths which are counted separately by [`gap`].
+const GAP_CATEGORIES: usize = 32;
+
+/// The gap test.
+///
+/// A value of [`RngCore::next_u64`] hits if its upper 3 bits are zero, that is,
+/// with probability 1/8. This measures the lengths of `gaps` gaps between
+/// consecutive hits, and counts the lengths from 0 to 31 and the lengths of 32
+/// or more in 33 categories. The statistic is the chi-square statistic with 32
+/// degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert!(quality::gap(&mut rng, 1 << 14).p_value() > 1e-6);
+/// ```
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss
+)]
+#[inline]
+pub fn gap<R: RngCore + ?Sized>(rng: &mut R, gaps: usize) -> TestResult {
+    const P: f64 = 1.0 / 8.0;
+
+    let mut counts = [u64::default(); GAP_CATEGORIES + 1];
+    for _ in 0..gaps {
+        let mut len = 0;
+        while rng.next_u64() >> 61 != 0 {
+            len += 1;
+        }
+        counts[len.min(GAP_CATEGORIES)] += 1;
+    }
+    let expected = probabilities::<{ GAP_CATEGORIES + 1 }>(|r| {
+        if r == GAP_CATEGORIES {
+            (1.0 - P).powi(r as i32)
+        } else {
+            P * (1.0 - P).powi(r as i32)
+        }
+    });
+    let statistic = chi_square(&counts, &expected);
+    TestResult::new(
+        "gap",
+        statistic,
+        chi_square_p_value(statistic, GAP_CATEGORIES as u64),
+    )
+}
+
+/// The number of birthdays in each sample of [`birthday_spacings`].
+const BIRTHDAYS: usize = 4096;
+
+/// The birthday spacings test.
+///
+/// Each sample takes 4096 values of [`RngCore::next_u32`] as birthdays in a
+/// year of 2<sup>32</sup> days, and counts the repeated values among the
+/// spacings between the sorted birthdays. The statistic is the total count of
+/// `samples` samples, which approximately follows the Poisson distribution with
+/// mean `4 * samples`. The p-value is two-sided.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert!(quality::birthday_spacings(&mut rng, 16).p_value() > 1e-6);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn birthday_spacings<R: RngCore + ?Sized>(rng: &mut R, samples: usize) -> TestResult {
+    // The mean number of repeated spacings per sample is m^3 / (4n), where m is
+    // the number of birthdays and n is the number of days, so this is
+    // 2^36 / 2^34.
+    const LAMBDA: f64 = 4.0;
+
+    let mut birthdays = [u32::default(); BIRTHDAYS];
+    let mut repeats = u64::default();
+    for _ in 0..samples {
+        birthdays.fill_with(|| rng.next_u32());
+        birthdays.sort_unstable();
+        let mut prev = 0;
+        for b in &mut birthdays {
+            (*b, prev) = (*b - prev, *b);
+        }
+        birthdays.sort_unstable();
+        repeats += birthdays.windows(2).filter(|w| w[0] == w[1]).count() as u64;
+    }
+    let mean = LAMBDA * samples as f64;
+    let lower = gamma_q(repeats as f64 + 1.0, mean);
+    let upper = if repeats == 0 {
+        1.0
+    } else {
+        gamma_p(repeats as f64, mean)
+    };
+    TestResult::new("birthday spacings", repeats as f64, 2.0 * lower.min(upper))
+}
+
+/// The number of bits in each block of [`linear_complexity`].
+const LINEAR_COMPLEXITY_BLOCK_LEN: usize = 500;
+
+/// The linear complexity test.
+///
+/// This splits the bits of [`RngCore::next_u64`] into `blocks` blocks of 500
+/// bits, and computes the linear complexity of each block by the
+/// Berlekamp–Massey algorithm. The deviations from the expected linear
+/// complexity are counted in 7 categories. The statistic is the chi-square
+/// statistic with 6 degrees of freedom.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert!(quality::linear_complexity(&mut rng, 200).p_value() > 1e-6);
+/// ```
+#[allow(clippy::cast_precision_loss)]
+#[inline]
+pub fn linear_complexity<R: RngCore + ?Sized>(rng: &mut R, blocks: usize) -> TestResult {
+    const M: usize = LINEAR_COMPLEXITY_BLOCK_LEN;
+    // The expected linear complexity of a random sequence of M bits, where M is
+    // even. The term of 2^-M is omitted.
+    const MEAN: f64 = M as f64 / 2.0 + 8.0 / 36.0;
+    const PROBABILITIES: [f64; 7] = [
+        1.0 / 96.0,
+        1.0 / 32.0,
+        1.0 / 8.0,
+        1.0 / 2.0,
+        1.0 / 4.0,
+        1.0 / 16.0,
+        1.0 / 48.0,
+    ];
+
+    let mut counts = [u64::default(); 7];
+    let (mut word, mut available) = (u64::default(), 0);
+    let mut bits = [u8::default(); M];
+    for _ in 0..blocks {
+        for bit in &mut bits {
+            if available == 0 {
+                (word, available) = (rng.next_u64(), 64);
+            }
+            *bit = (word & 1) as u8;
+            (word, available) = (word >> 1, available - 1);
+        }
+        let t = berlekamp_massey(&bits) as f64 - MEAN + 2.0 / 9.0;
+        let category = match t {
+            t if t <= -2.5 => 0,
+            t if t <= -1.5 => 1,
+            t if t <= -0.5 => 2,
+            t if t <= 0.5 => 3,
+            t if t <= 1.5 => 4,
+            t if t <= 2.5 => 5,
+            _ => 6,
+        };
+        counts[category] += 1;
+    }
+    let statistic = chi_square(&counts, &PROBABILITIES);
+    TestResult::new(
+        "linear complexity",
+        statistic,
+        chi_square_p_value(statistic, 6),
+    )
+}
+
+/// A generator which interleaves the outputs of several generators.
+///
+/// Each call of [`RngCore::next_u32`] or [`RngCore::next_u64`] takes a value
+/// from the next generator in turn, and [`RngCore::fill_bytes`] takes a
+/// [`u64`] value from each generator in turn. Running the tests in this module
+/// on the merged stream detects correlations between generators with related
+/// seeds, such as ones seeded with consecutive integers for parallel workers,
+/// which do not show up in each stream alone.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, quality::{self, Interleaved}};
+/// #
+/// let rngs = (0..64).map(|seed| Sfc64::new_u64(seed, None)).collect();
+/// let mut rng = Interleaved::new(rngs);
+/// assert!(quality::monobit(&mut rng, 1 << 12).p_value() > 1e-6);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Interleaved<R> {
+    rngs: Vec<R>,
+    next: usize,
+}
+
+impl<R: RngCore> Interleaved<R> {
+    /// Creates a new `Interleaved` which takes the outputs of `rngs` in turn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rngs` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc32, quality::Interleaved};
+    /// #
+    /// let rngs = (0..4).map(|seed| Sfc32::new_u64(seed, None)).collect();
+    /// let rng = Interleaved::new(rngs);
+    /// assert_eq!(rng.rngs().len(), 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(rngs: Vec<R>) -> Self {
+        assert!(!rngs.is_empty(), "at least one generator is required");
+        Self { rngs, next: 0 }
+    }
+
+    /// Returns the generators.
+    #[must_use]
+    #[inline]
+    pub fn rngs(&self) -> &[R] {
+        &self.rngs
+    }
+
+    /// Consumes the `Interleaved`, returning the generators.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Vec<R> {
+        self.rngs
+    }
+
+    /// Returns the generator whose turn it is, and passes the turn to the next
+    /// one.
+    fn turn(&mut self) -> &mut R {
+        let index = self.next;
+        self.next = (index + 1) % self.rngs.len();
+        &mut self.rngs[index]
+    }
+}
+
+impl<R: RngCore> RngCore for Interleaved<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.turn().next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.turn().next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+/// Returns the length of the shortest linear feedback shift register which
+/// generates `bits`.
+#[allow(clippy::many_single_char_names)]
+fn berlekamp_massey(bits: &[u8; LINEAR_COMPLEXITY_BLOCK_LEN]) -> usize {
+    const N: usize = LINEAR_COMPLEXITY_BLOCK_LEN;
+
+    let (mut c, mut b) = ([u8::default(); N + 1], [u8::default(); N + 1]);
+    (c[0], b[0]) = (1, 1);
+    let (mut len, mut m) = (0, 0);
+    for i in 0..N {
+        let d = (1..=len).fold(bits[i], |d, j| d ^ (c[j] & bits[i - j]));
+        if d == 1 {
+            let t = c;
+            let shift = i + 1 - m;
+            for j in 0..=N - shift {
+                c[j + shift] ^= b[j];
+            }
+            if 2 * len <= i {
+                (len, m, b) = (i + 1 - len, i + 1, t);
+            }
+        }
+    }
+    len
+}
+
+/// Returns an array of `N` probabilities computed by `f`.
+fn probabilities<const N: usize>(f: impl FnMut(usize) -> f64) -> [f64; N] {
+    core::array::from_fn(f)
+}
+
+/// Returns the chi-square statistic of `counts` against `probabilities`.
+#[allow(clippy::cast_precision_loss)]
+fn chi_square(counts: &[u64], probabilities: &[f64]) -> f64 {
+    let n = counts.iter().sum::<u64>() as f64;
+    counts
+        .iter()
+        .zip(probabilities)
+        .map(|(&o, &p)| {
+            let e = n * p;
+            (o as f64 - e).powi(2) / e
+        })
+        .sum()
+}
+
+/// Returns the upper tail probability of the chi-square distribution.
+#[allow(clippy::cast_precision_loss)]
+fn chi_square_p_value(statistic: f64, degrees_of_freedom: u64) -> f64 {
+    gamma_q(degrees_of_freedom as f64 / 2.0, statistic / 2.0)
+}
+
+/// Returns the complementary error function of `x`, where `x >= 0`.
+fn erfc(x: f64) -> f64 {
+    gamma_q(0.5, x * x)
+}
+
+/// Returns the natural logarithm of the gamma function of `x`, where `x >=
+/// 0.5`.
+///
+/// This uses the Lanczos approximation with `g = 7` and `n = 9`.
+#[allow(clippy::cast_precision_loss)]
+fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    let x = x - 1.0;
+    let sum = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .skip(1)
+        .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64));
+    let t = x + G + 0.5;
+    0.5f64.mul_add((2.0 * PI).ln(), (x + 0.5) * t.ln()) - t + sum.ln()
+}
+
+/// The maximum number of iterations of the series and the continued fraction
+/// of the incomplete gamma function.
+const MAX_ITERATIONS: u32 = 1000;
+
+/// Returns the regularized lower incomplete gamma function `P(a, x)`.
+fn gamma_p(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        0.0
+    } else if x < a + 1.0 {
+        gamma_series(a, x)
+    } else {
+        1.0 - gamma_continued_fraction(a, x)
+    }
+}
+
+/// Returns the regularized upper incomplete gamma function `Q(a, x)`.
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        1.0
+    } else if x < a + 1.0 {
+        1.0 - gamma_series(a, x)
+    } else {
+        gamma_continued_fraction(a, x)
+    }
+}
+
+/// Returns `P(a, x)` by the series, which converges quickly for `x < a + 1`.
+fn gamma_series(a: f64, x: f64) -> f64 {
+    let (mut ap, mut term) = (a, 1.0 / a);
+    let mut sum = term;
+    for _ in 0..MAX_ITERATIONS {
+        ap += 1.0;
+        term *= x / ap;
+        sum += term;
+        if term.abs() < sum.abs() * f64::EPSILON {
+            break;
+        }
+    }
+    sum * a.mul_add(x.ln(), -x - ln_gamma(a)).exp()
+}
+
+/// Returns `Q(a, x)` by the continued fraction, which converges quickly for
+/// `x >= a + 1`.
+///
+/// This uses the modified Lentz's method.
+#[allow(clippy::many_single_char_names)]
+fn gamma_continued_fraction(a: f64, x: f64) -> f64 {
+    const TINY: f64 = f64::MIN_POSITIVE / f64::EPSILON;
+
+    let mut b = x + 1.0 - a;
+    let mut c = 1.0 / TINY;
+    let mut d = 1.0 / b;
+    let mut h = d;
+    for i in 1..=MAX_ITERATIONS {
+        let i = f64::from(i);
+        let an = -i * (i - a);
+        b += 2.0;
+        d = an.mul_add(d, b);
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = b + an / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < f64::EPSILON {
+            break;
+        }
+    }
+    a.mul_add(x.ln(), -x - ln_gamma(a)).exp() * h
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::Sfc64;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn special_functions() {
+        assert_close(ln_gamma(1.0), 0.0);
+        assert_close(ln_gamma(0.5), PI.sqrt().ln());
+        assert_close(ln_gamma(10.0), 362_880.0_f64.ln());
+        assert_close(erfc(0.0), 1.0);
+        assert_close(erfc(1.0), 0.157_299_207_050_285_13);
+        assert_close(erfc(3.0), 2.209_049_699_858_544e-5);
+        for x in [0.1, 1.0, 5.0, 50.0] {
+            assert_close(gamma_q(1.0, x), (-x).exp());
+            assert_close(gamma_p(1.0, x) + gamma_q(1.0, x), 1.0);
+        }
+        // The chi-square distribution with 2 degrees of freedom is the
+        // exponential distribution with mean 2.
+        assert_close(chi_square_p_value(3.0, 2), (-1.5_f64).exp());
+        assert_close(chi_square_p_value(255.0, 255), 0.488_222_521_770_406);
+    }
+
+    #[test]
+    fn berlekamp_massey() {
+        let mut bits = [u8::default(); LINEAR_COMPLEXITY_BLOCK_LEN];
+        assert_eq!(super::berlekamp_massey(&bits), 0);
+        bits[LINEAR_COMPLEXITY_BLOCK_LEN - 1] = 1;
+        assert_eq!(super::berlekamp_massey(&bits), LINEAR_COMPLEXITY_BLOCK_LEN);
+        // The Fibonacci sequence modulo 2 is generated by an LFSR of length 2.
+        let mut bits = [1; LINEAR_COMPLEXITY_BLOCK_LEN];
+        for i in 2..LINEAR_COMPLEXITY_BLOCK_LEN {
+            bits[i] = bits[i - 1] ^ bits[i - 2];
+        }
+        assert_eq!(super::berlekamp_massey(&bits), 2);
+    }
+
+    /// A generator which returns consecutive integers.
+    struct Counter(u64);
+
+    impl RngCore for Counter {
+        #[allow(clippy::cast_possible_truncation)]
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(1);
+            self.0
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst);
+        }
+    }
+
+    /// The sfc64 algorithm with the given rotation, right shift and left shift
+    /// constants, which is seeded in the same way as [`Sfc64::new_u64`].
+    struct Sfc64With {
+        state: [u64; 4],
+        constants: [u32; 3],
+    }
+
+    impl Sfc64With {
+        /// The constants of sfc64.
+        const SFC64: [u32; 3] = [24, 11, 3];
+
+        fn new_u64(constants: [u32; 3], seed: u64, rounds: u64) -> Self {
+            let mut rng = Self {
+                state: [seed, seed, seed, 1],
+                constants,
+            };
+            for _ in 0..rounds {
+                rng.next_u64();
+            }
+            rng
+        }
+    }
+
+    impl RngCore for Sfc64With {
+        #[allow(clippy::cast_possible_truncation)]
+        fn next_u32(&mut self) -> u32 {
+            self.next_u64() as u32
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let [a, b, c, counter] = self.state;
+            let [rotation, right_shift, left_shift] = self.constants;
+            let tmp = a.wrapping_add(b).wrapping_add(counter);
+            self.state = [
+                b ^ (b >> right_shift),
+                c.wrapping_add(c << left_shift),
+                c.rotate_left(rotation).wrapping_add(tmp),
+                counter.wrapping_add(1),
+            ];
+            tmp
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dst);
+        }
+    }
+
+    #[test]
+    fn sfc64_with() {
+        let mut rng = Sfc64With::new_u64(Sfc64With::SFC64, 1, 4);
+        let mut expected = Sfc64::new_u64(1, Some(4));
+        for _ in 0..16 {
+            assert_eq!(rng.next_u64(), expected.next_u64());
+        }
+    }
+
+    #[test]
+    fn broken_generators_fail() {
+        let results = quick_check(&mut Counter(u64::default()));
+        assert!(results.iter().any(|r| r.p_value() < 1e-10));
+        let results = quick_check(&mut Sfc64With::new_u64([1, 1, 1], 1, 12));
+        assert!(results.iter().any(|r| r.p_value() < 1e-10));
+    }
+
+    #[test]
+    fn wrong_rotation_fails_with_neighbouring_seeds() {
+        // A single wrong constant passes on one stream, but the generators
+        // with neighbouring seeds are still correlated after 4 rounds with the
+        // rotation of sfc32 (21), while they are not with that of sfc64.
+        let interleaved = |constants| {
+            let rngs = (0..4096).map(|seed| Sfc64With::new_u64(constants, seed, 4));
+            Interleaved::new(rngs.collect())
+        };
+        let [_, right_shift, left_shift] = Sfc64With::SFC64;
+        let mut rng = interleaved([21, right_shift, left_shift]);
+        assert!(monobit(&mut rng, 1 << 16).p_value() < 1e-10);
+        let mut rng = interleaved(Sfc64With::SFC64);
+        assert!(monobit(&mut rng, 1 << 16).p_value() > 1e-6);
+    }
+
+    #[test]
+    fn interleaved() {
+        let rngs = (0..3).map(|i| Counter(i * 100)).collect();
+        let mut rng = Interleaved::new(rngs);
+        let outputs: [u64; 7] = core::array::from_fn(|_| rng.next_u64());
+        assert_eq!(outputs, [1, 101, 201, 2, 102, 202, 3]);
+        assert_eq!(rng.next_u32(), 103);
+        assert_eq!(rng.into_inner().len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one generator is required")]
+    fn interleaved_without_generators() {
+        let _ = Interleaved::<Sfc64>::new(Vec::new());
+    }
+
+    #[test]
+    fn interleaved_neighbouring_seeds() {
+        let interleaved = |rounds| {
+            let rngs = (0..4096).map(|seed| Sfc64::new_u64(seed, rounds));
+            Interleaved::new(rngs.collect())
+        };
+        assert!(monobit(&mut interleaved(Some(0)), 1 << 16).p_value() < 1e-10);
+        assert!(monobit(&mut interleaved(None), 1 << 16).p_value() > 1e-6);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            TestResult::new("monobit", 0.5, 0.25).to_string(),
+            "monobit: statistic = 0.5, p-value = 0.25"
+        );
+    }
+}
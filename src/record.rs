@@ -0,0 +1,796 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Wrappers which record the calls to a random number generator and replay
+//! them.
+
+use alloc::vec::Vec;
+use core::{error::Error, fmt};
+
+use rand_core::{RngCore, TryRngCore};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The kind of a call to a random number generator.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::CallKind;
+/// #
+/// assert_eq!(CallKind::NextU64.to_string(), "next_u64");
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum CallKind {
+    /// A call to [`RngCore::next_u32`].
+    NextU32,
+
+    /// A call to [`RngCore::next_u64`].
+    NextU64,
+
+    /// A call to [`RngCore::fill_bytes`].
+    FillBytes,
+}
+
+impl fmt::Display for CallKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NextU32 => write!(f, "next_u32"),
+            Self::NextU64 => write!(f, "next_u64"),
+            Self::FillBytes => write!(f, "fill_bytes"),
+        }
+    }
+}
+
+/// A call to a random number generator and its result.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Call, CallKind};
+/// #
+/// assert_eq!(Call::FillBytes(vec![0; 4]).kind(), CallKind::FillBytes);
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub enum Call {
+    /// A call to [`RngCore::next_u32`] and the returned value.
+    NextU32(u32),
+
+    /// A call to [`RngCore::next_u64`] and the returned value.
+    NextU64(u64),
+
+    /// A call to [`RngCore::fill_bytes`] and the written bytes.
+    FillBytes(Vec<u8>),
+}
+
+impl Call {
+    /// Returns the kind of this call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Call, CallKind};
+    /// #
+    /// assert_eq!(Call::NextU32(0).kind(), CallKind::NextU32);
+    /// assert_eq!(Call::NextU64(0).kind(), CallKind::NextU64);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> CallKind {
+        match self {
+            Self::NextU32(_) => CallKind::NextU32,
+            Self::NextU64(_) => CallKind::NextU64,
+            Self::FillBytes(_) => CallKind::FillBytes,
+        }
+    }
+}
+
+/// The function which clones the generator and the recorded states.
+type Snapshots<R> = (fn(&R) -> R, Vec<R>);
+
+/// A wrapper which records every call to a random number generator and its
+/// result.
+///
+/// The recorded calls can be turned into a [`Replay`], which returns the same
+/// values while checking that the same calls happen in the same order.
+///
+/// This works with any [`RngCore`]. If the generator implements [`Clone`],
+/// [`with_snapshots`](Self::with_snapshots) also records the state of the
+/// generator before each call. For the SFC generators, a snapshot can be used
+/// to reproduce the values from the call where two runs diverge.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{
+/// #     Call, Recording, Sfc64,
+/// #     rand_core::{RngCore, SeedableRng, TryRngCore},
+/// # };
+/// #
+/// let mut rng = Recording::new(Sfc64::seed_from_u64(0));
+/// let x = rng.next_u64();
+/// assert_eq!(rng.calls(), [Call::NextU64(x)]);
+///
+/// let mut replay = rng.into_replay();
+/// assert_eq!(replay.try_next_u64(), Ok(x));
+/// assert!(replay.is_finished());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug)]
+pub struct Recording<R> {
+    rng: R,
+    calls: Vec<Call>,
+    snapshots: Option<Snapshots<R>>,
+}
+
+impl<R> Recording<R> {
+    /// Creates a new `Recording` which wraps `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Recording, Sfc64, rand_core::SeedableRng};
+    /// #
+    /// let rng = Recording::new(Sfc64::seed_from_u64(0));
+    /// assert!(rng.calls().is_empty());
+    /// assert_eq!(rng.snapshots(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(rng: R) -> Self {
+        Self {
+            rng,
+            calls: Vec::new(),
+            snapshots: None,
+        }
+    }
+
+    /// Creates a new `Recording` which wraps `rng`, and also records the state
+    /// of `rng` before each call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{
+    /// #     Recording, Sfc64,
+    /// #     rand_core::{RngCore, SeedableRng},
+    /// # };
+    /// #
+    /// let mut rng = Recording::with_snapshots(Sfc64::seed_from_u64(0));
+    /// let x = rng.next_u64();
+    ///
+    /// let mut snapshot = rng.snapshots().unwrap()[0].clone();
+    /// assert_eq!(snapshot, Sfc64::seed_from_u64(0));
+    /// assert_eq!(snapshot.next_u64(), x);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn with_snapshots(rng: R) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            rng,
+            calls: Vec::new(),
+            snapshots: Some((R::clone, Vec::new())),
+        }
+    }
+
+    /// Returns the recorded calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{
+    /// #     Call, Recording, Sfc64,
+    /// #     rand_core::{RngCore, SeedableRng},
+    /// # };
+    /// #
+    /// let mut rng = Recording::new(Sfc64::seed_from_u64(0));
+    /// let x = rng.next_u32();
+    /// assert_eq!(rng.calls(), [Call::NextU32(x)]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Returns the recorded states, or [`None`] if this was not created by
+    /// [`with_snapshots`](Self::with_snapshots).
+    ///
+    /// The snapshot at index `i` is the state before the call at index `i`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{
+    /// #     Recording, Sfc64,
+    /// #     rand_core::{RngCore, SeedableRng},
+    /// # };
+    /// #
+    /// let mut rng = Recording::with_snapshots(Sfc64::seed_from_u64(0));
+    /// rng.next_u64();
+    /// rng.next_u64();
+    /// assert_eq!(rng.snapshots().map(<[_]>::len), Some(2));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn snapshots(&self) -> Option<&[R]> {
+        self.snapshots
+            .as_ref()
+            .map(|(_, snapshots)| snapshots.as_slice())
+    }
+
+    /// Returns a reference to the underlying generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Recording, Sfc64, rand_core::SeedableRng};
+    /// #
+    /// let rng = Recording::new(Sfc64::seed_from_u64(0));
+    /// assert_eq!(rng.rng(), &Sfc64::seed_from_u64(0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn rng(&self) -> &R {
+        &self.rng
+    }
+
+    /// Returns a [`Replay`] of the recorded calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{
+    /// #     Recording, Sfc64,
+    /// #     rand_core::{RngCore, SeedableRng, TryRngCore},
+    /// # };
+    /// #
+    /// let mut rng = Recording::new(Sfc64::seed_from_u64(0));
+    /// let x = rng.next_u64();
+    /// assert_eq!(rng.replay().try_next_u64(), Ok(x));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn replay(&self) -> Replay {
+        Replay::new(self.calls.clone())
+    }
+
+    /// Consumes the `Recording`, returning a [`Replay`] of the recorded calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{
+    /// #     Recording, Sfc64,
+    /// #     rand_core::{RngCore, SeedableRng, TryRngCore},
+    /// # };
+    /// #
+    /// let mut rng = Recording::new(Sfc64::seed_from_u64(0));
+    /// let x = rng.next_u64();
+    /// assert_eq!(rng.into_replay().try_next_u64(), Ok(x));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_replay(self) -> Replay {
+        Replay::new(self.calls)
+    }
+
+    /// Consumes the `Recording`, returning the underlying generator and the
+    /// recorded calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{
+    /// #     Call, Recording, Sfc64,
+    /// #     rand_core::{RngCore, SeedableRng},
+    /// # };
+    /// #
+    /// let mut rng = Recording::new(Sfc64::seed_from_u64(0));
+    /// let x = rng.next_u64();
+    ///
+    /// let (mut rng, calls) = rng.into_parts();
+    /// assert_eq!(calls, [Call::NextU64(x)]);
+    /// assert_ne!(rng.next_u64(), x);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_parts(self) -> (R, Vec<Call>) {
+        (self.rng, self.calls)
+    }
+
+    fn snapshot(&mut self) {
+        if let Some((clone, snapshots)) = &mut self.snapshots {
+            snapshots.push(clone(&self.rng));
+        }
+    }
+}
+
+impl<R: RngCore> RngCore for Recording<R> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.snapshot();
+        let value = self.rng.next_u32();
+        self.calls.push(Call::NextU32(value));
+        value
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.snapshot();
+        let value = self.rng.next_u64();
+        self.calls.push(Call::NextU64(value));
+        value
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.snapshot();
+        self.rng.fill_bytes(dst);
+        self.calls.push(Call::FillBytes(dst.to_vec()));
+    }
+}
+
+/// The error returned by [`Replay`] when a call differs from the recorded one.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{CallKind, ReplayError};
+/// #
+/// let err = ReplayError::KindMismatch {
+///     index: 2,
+///     expected: CallKind::NextU64,
+///     found: CallKind::NextU32,
+/// };
+/// assert_eq!(
+///     err.to_string(),
+///     "call 2 was `next_u32`, but `next_u64` was recorded"
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReplayError {
+    /// The kind of the call differs from the recorded one.
+    KindMismatch {
+        /// The index of the call.
+        index: usize,
+
+        /// The kind of the recorded call.
+        expected: CallKind,
+
+        /// The kind of the actual call.
+        found: CallKind,
+    },
+
+    /// The length of the buffer passed to [`fill_bytes`](RngCore::fill_bytes)
+    /// differs from the recorded one.
+    LengthMismatch {
+        /// The index of the call.
+        index: usize,
+
+        /// The length of the recorded buffer.
+        expected: usize,
+
+        /// The length of the actual buffer.
+        found: usize,
+    },
+
+    /// All of the recorded calls have already been replayed.
+    Exhausted {
+        /// The index of the call, which is also the number of the recorded
+        /// calls.
+        index: usize,
+
+        /// The kind of the actual call.
+        found: CallKind,
+    },
+}
+
+impl fmt::Display for ReplayError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KindMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "call {index} was `{found}`, but `{expected}` was recorded"
+            ),
+            Self::LengthMismatch {
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "call {index} was `fill_bytes` with {found} bytes, but {expected} bytes were recorded"
+            ),
+            Self::Exhausted { index, found } => write!(
+                f,
+                "call {index} was `{found}`, but only {index} calls were recorded"
+            ),
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+/// A generator which returns the values recorded by [`Recording`].
+///
+/// Each call must be of the same kind as the recorded call at the same index,
+/// and [`try_fill_bytes`](TryRngCore::try_fill_bytes) must be called with a
+/// buffer of the same length. Otherwise, the call returns [`ReplayError`]
+/// without advancing, and no values are written.
+///
+/// Since [`RngCore`] cannot return an error, this only implements
+/// [`TryRngCore`]. Use [`UnwrapErr`](rand_core::UnwrapErr) to panic instead.
+///
+/// With the `serde` feature, a deserialized `Replay` is checked to have a
+/// position which is not greater than the number of the recorded calls.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{
+/// #     CallKind, Recording, ReplayError, Sfc64,
+/// #     rand_core::{RngCore, SeedableRng, TryRngCore},
+/// # };
+/// #
+/// let mut rng = Recording::new(Sfc64::seed_from_u64(0));
+/// let x = rng.next_u64();
+///
+/// let mut replay = rng.into_replay();
+/// assert_eq!(
+///     replay.try_next_u32(),
+///     Err(ReplayError::KindMismatch {
+///         index: 0,
+///         expected: CallKind::NextU64,
+///         found: CallKind::NextU32
+///     })
+/// );
+/// assert_eq!(replay.try_next_u64(), Ok(x));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ReplayRepr"))]
+pub struct Replay {
+    calls: Vec<Call>,
+    position: usize,
+}
+
+impl Replay {
+    /// Creates a new `Replay` which returns the values of `calls`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Call, Replay, rand_core::TryRngCore};
+    /// #
+    /// let mut replay = Replay::new(vec![Call::NextU32(42)]);
+    /// assert_eq!(replay.try_next_u32(), Ok(42));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(calls: Vec<Call>) -> Self {
+        Self { calls, position: 0 }
+    }
+
+    /// Returns the index of the next call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Call, Replay, rand_core::TryRngCore};
+    /// #
+    /// let mut replay = Replay::new(vec![Call::NextU32(42)]);
+    /// assert_eq!(replay.position(), 0);
+    /// replay.try_next_u32().unwrap();
+    /// assert_eq!(replay.position(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns [`true`] if all of the recorded calls have been replayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Call, Replay, rand_core::TryRngCore};
+    /// #
+    /// let mut replay = Replay::new(vec![Call::NextU32(42)]);
+    /// assert!(!replay.is_finished());
+    /// replay.try_next_u32().unwrap();
+    /// assert!(replay.is_finished());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.position == self.calls.len()
+    }
+
+    /// Returns the recorded calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Call, Replay};
+    /// #
+    /// let replay = Replay::new(vec![Call::NextU32(42)]);
+    /// assert_eq!(replay.calls(), [Call::NextU32(42)]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn calls(&self) -> &[Call] {
+        &self.calls
+    }
+
+    /// Returns the error for a call of the kind `found` when the call at the
+    /// current position is `call`, which is not of that kind.
+    fn mismatch(&self, call: Option<&Call>, found: CallKind) -> ReplayError {
+        let index = self.position;
+        call.map_or(ReplayError::Exhausted { index, found }, |call| {
+            ReplayError::KindMismatch {
+                index,
+                expected: call.kind(),
+                found,
+            }
+        })
+    }
+}
+
+impl TryRngCore for Replay {
+    type Error = ReplayError;
+
+    #[inline]
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let value = match self.calls.get(self.position) {
+            Some(Call::NextU32(value)) => *value,
+            call => return Err(self.mismatch(call, CallKind::NextU32)),
+        };
+        self.position += 1;
+        Ok(value)
+    }
+
+    #[inline]
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let value = match self.calls.get(self.position) {
+            Some(Call::NextU64(value)) => *value,
+            call => return Err(self.mismatch(call, CallKind::NextU64)),
+        };
+        self.position += 1;
+        Ok(value)
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        match self.calls.get(self.position) {
+            Some(Call::FillBytes(bytes)) if bytes.len() == dst.len() => dst.copy_from_slice(bytes),
+            Some(Call::FillBytes(bytes)) => {
+                return Err(ReplayError::LengthMismatch {
+                    index: self.position,
+                    expected: bytes.len(),
+                    found: dst.len(),
+                });
+            }
+            call => return Err(self.mismatch(call, CallKind::FillBytes)),
+        }
+        self.position += 1;
+        Ok(())
+    }
+}
+
+/// The serialized form of [`Replay`], which is checked before being converted.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct ReplayRepr {
+    calls: Vec<Call>,
+    position: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ReplayRepr> for Replay {
+    type Error = &'static str;
+
+    fn try_from(repr: ReplayRepr) -> Result<Self, Self::Error> {
+        if repr.position > repr.calls.len() {
+            return Err("the position must not be greater than the number of calls");
+        }
+        Ok(Self {
+            calls: repr.calls,
+            position: repr.position,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use rand_core::{SeedableRng, UnwrapErr};
+
+    use super::*;
+    use crate::{Sfc32, Sfc64};
+
+    fn record<R: RngCore>(rng: &mut R) {
+        rng.next_u32();
+        rng.next_u64();
+        rng.fill_bytes(&mut [u8::default(); 13]);
+        rng.next_u64();
+    }
+
+    #[test]
+    fn recording() {
+        let mut rng = Recording::new(Sfc64::from_seed(Default::default()));
+        record(&mut rng);
+
+        let mut expected = Sfc64::from_seed(Default::default());
+        let mut bytes = vec![u8::default(); 13];
+        let calls = [
+            Call::NextU32(expected.next_u32()),
+            Call::NextU64(expected.next_u64()),
+            Call::FillBytes({
+                expected.fill_bytes(&mut bytes);
+                bytes
+            }),
+            Call::NextU64(expected.next_u64()),
+        ];
+        assert_eq!(rng.calls(), calls);
+        assert_eq!(rng.rng(), &expected);
+        assert_eq!(rng.snapshots(), None);
+    }
+
+    #[test]
+    fn snapshots() {
+        let mut rng = Recording::with_snapshots(Sfc32::from_seed(Default::default()));
+        record(&mut rng);
+
+        let snapshots = rng.snapshots().unwrap();
+        assert_eq!(snapshots.len(), rng.calls().len());
+        for (snapshot, call) in snapshots.iter().zip(rng.calls()) {
+            let mut snapshot = snapshot.clone();
+            let actual = match call {
+                Call::NextU32(_) => Call::NextU32(snapshot.next_u32()),
+                Call::NextU64(_) => Call::NextU64(snapshot.next_u64()),
+                Call::FillBytes(bytes) => {
+                    let mut buf = vec![u8::default(); bytes.len()];
+                    snapshot.fill_bytes(&mut buf);
+                    Call::FillBytes(buf)
+                }
+            };
+            assert_eq!(&actual, call);
+        }
+    }
+
+    #[test]
+    fn replay() {
+        let mut rng = Recording::new(Sfc64::from_seed(Default::default()));
+        record(&mut rng);
+        let calls = rng.calls().to_vec();
+
+        let mut replay = UnwrapErr(rng.into_replay());
+        let mut recording = Recording::new(&mut replay);
+        record(&mut recording);
+        assert_eq!(recording.calls(), calls);
+        assert!(replay.0.is_finished());
+    }
+
+    #[test]
+    fn replay_kind_mismatch() {
+        let mut replay = Replay::new(vec![Call::NextU32(1), Call::NextU64(2)]);
+        assert_eq!(
+            replay.try_next_u64(),
+            Err(ReplayError::KindMismatch {
+                index: 0,
+                expected: CallKind::NextU32,
+                found: CallKind::NextU64
+            })
+        );
+        assert_eq!(
+            replay.try_fill_bytes(&mut [u8::default(); 4]),
+            Err(ReplayError::KindMismatch {
+                index: 0,
+                expected: CallKind::NextU32,
+                found: CallKind::FillBytes
+            })
+        );
+        assert_eq!(replay.position(), 0);
+        assert_eq!(replay.try_next_u32(), Ok(1));
+        assert_eq!(replay.try_next_u64(), Ok(2));
+    }
+
+    #[test]
+    fn replay_length_mismatch() {
+        let mut replay = Replay::new(vec![Call::FillBytes(vec![1, 2, 3])]);
+        let mut dst = [u8::default(); 4];
+        assert_eq!(
+            replay.try_fill_bytes(&mut dst),
+            Err(ReplayError::LengthMismatch {
+                index: 0,
+                expected: 3,
+                found: 4
+            })
+        );
+        assert_eq!(dst, [u8::default(); 4]);
+        let mut dst = [u8::default(); 3];
+        assert_eq!(replay.try_fill_bytes(&mut dst), Ok(()));
+        assert_eq!(dst, [1, 2, 3]);
+    }
+
+    #[test]
+    fn replay_exhausted() {
+        let mut replay = Replay::new(vec![Call::NextU32(1)]);
+        assert_eq!(replay.try_next_u32(), Ok(1));
+        assert_eq!(
+            replay.try_next_u32(),
+            Err(ReplayError::Exhausted {
+                index: 1,
+                found: CallKind::NextU32
+            })
+        );
+        assert_eq!(replay.position(), 1);
+    }
+
+    #[test]
+    fn replay_error_display() {
+        assert_eq!(
+            ReplayError::LengthMismatch {
+                index: 3,
+                expected: 8,
+                found: 16
+            }
+            .to_string(),
+            "call 3 was `fill_bytes` with 16 bytes, but 8 bytes were recorded"
+        );
+        assert_eq!(
+            ReplayError::Exhausted {
+                index: 5,
+                found: CallKind::FillBytes
+            }
+            .to_string(),
+            "call 5 was `fill_bytes`, but only 5 calls were recorded"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let replay = Replay::new(vec![Call::NextU32(1), Call::FillBytes(vec![2, 3])]);
+
+        let json = serde_json::to_string(&replay).unwrap();
+        assert_eq!(
+            json,
+            r#"{"calls":[{"NextU32":1},{"FillBytes":[2,3]}],"position":0}"#
+        );
+
+        let deserialized = serde_json::from_str::<Replay>(&json).unwrap();
+        assert_eq!(deserialized, replay);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid() {
+        let json = r#"{"calls":[{"NextU32":1}],"position":2}"#;
+        let error = serde_json::from_str::<Replay>(json).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "the position must not be greater than the number of calls"
+        );
+        let json = r#"{"calls":[{"NextU32":1}],"position":1}"#;
+        assert!(serde_json::from_str::<Replay>(json).unwrap().is_finished());
+    }
+}
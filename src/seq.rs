@@ -0,0 +1,315 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Sequence-related algorithms with a stable output.
+//!
+//! Unlike the algorithms of the [`rand`](https://crates.io/crates/rand) crate,
+//! the algorithms in this module are covered by the [value stability
+//! guarantee](crate#value-stability): for a given state of the random number
+//! generator, they always produce the same result, except in a semver-major
+//! release.
+//!
+//! All random indices are generated in the same way as
+//! [`Sfc64::gen_below`](crate::Sfc64::gen_below) with a [`usize`] bound.
+//!
+//! # Examples
+//!
+//! ```
+//! use sfc_prng::{Sfc64, seq};
+//!
+//! let mut rng = Sfc64::new_u64(0, None);
+//! let mut v = [1, 2, 3, 4, 5];
+//! seq::shuffle(&mut v, &mut rng);
+//! assert_eq!(v, [1, 3, 2, 4, 5]);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use rand_core::RngCore;
+
+use crate::BoundedInt;
+
+/// Shuffles `slice` in place.
+///
+/// This uses the Fisher–Yates shuffle: for each index `i` from `slice.len() -
+/// 1` down to 1, the element at `i` is swapped with the element at a random
+/// index in `0..=i`.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc32, seq};
+/// #
+/// let mut rng = Sfc32::new_u64(0, None);
+/// let mut v = [1, 2, 3, 4, 5];
+/// seq::shuffle(&mut v, &mut rng);
+/// assert_eq!(v, [3, 5, 4, 1, 2]);
+/// ```
+#[inline]
+pub fn shuffle<T, R: RngCore + ?Sized>(slice: &mut [T], rng: &mut R) {
+    for i in (1..slice.len()).rev() {
+        let j = usize::sample_below(rng, i + 1);
+        slice.swap(i, j);
+    }
+}
+
+/// Returns a reference to a random element of `slice`.
+///
+/// Returns [`None`] if `slice` is empty, in which case `rng` is not used.
+/// Otherwise, this uses one random index in `0..slice.len()`.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, seq};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert_eq!(seq::choose(&[1, 2, 3, 4, 5], &mut rng), Some(&5));
+/// assert_eq!(seq::choose::<i32, _>(&[], &mut rng), None);
+/// ```
+#[inline]
+pub fn choose<'a, T, R: RngCore + ?Sized>(slice: &'a [T], rng: &mut R) -> Option<&'a T> {
+    if slice.is_empty() {
+        None
+    } else {
+        slice.get(usize::sample_below(rng, slice.len()))
+    }
+}
+
+/// Returns a random element of `iter`.
+///
+/// Returns [`None`] if `iter` is empty. This uses reservoir sampling, so `iter`
+/// is consumed once without knowing its length: the `i`-th element (counting
+/// from 0) replaces the chosen element if a random index in `0..=i` is 0. The
+/// first element is always chosen without using `rng`.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, seq};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert_eq!(seq::choose_iter(1..=5, &mut rng), Some(1));
+/// ```
+#[inline]
+pub fn choose_iter<I: IntoIterator, R: RngCore + ?Sized>(iter: I, rng: &mut R) -> Option<I::Item> {
+    let mut iter = iter.into_iter();
+    let mut chosen = iter.next()?;
+    for (i, item) in iter.enumerate() {
+        if usize::sample_below(rng, i + 2) == 0 {
+            chosen = item;
+        }
+    }
+    Some(chosen)
+}
+
+/// Returns `amount` distinct random indices in `0..length`.
+///
+/// This uses Robert Floyd's sampling algorithm: for each `j` in `length -
+/// amount..length`, a random index `t` in `0..=j` is generated. If `t` has
+/// already been chosen, its position is set to `j`. Then `t` is appended. As a
+/// result, the order of the returned indices is also random.
+///
+/// This takes `O(amount^2)` time, so it is intended for a small `amount`.
+///
+/// # Panics
+///
+/// Panics if `amount` is greater than `length`.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, seq};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert_eq!(seq::sample_indices(10, 3, &mut rng), [7, 8, 5]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn sample_indices<R: RngCore + ?Sized>(
+    length: usize,
+    amount: usize,
+    rng: &mut R,
+) -> Vec<usize> {
+    assert!(
+        amount <= length,
+        "`amount` must be less than or equal to `length`"
+    );
+    let mut indices = Vec::with_capacity(amount);
+    for j in (length - amount)..length {
+        let t = usize::sample_below(rng, j + 1);
+        if let Some(pos) = indices.iter().position(|&i| i == t) {
+            indices[pos] = j;
+        }
+        indices.push(t);
+    }
+    indices
+}
+
+/// Returns `amount` random elements of `iter`.
+///
+/// This uses reservoir sampling (Algorithm R), so `iter` is consumed once
+/// without knowing its length. The first `amount` elements are taken as they
+/// are. After that, for the `i`-th element (counting from 0), a random index
+/// `j` in `0..=i` is generated, and the element replaces the `j`-th chosen
+/// element if `j` is less than `amount`.
+///
+/// If `iter` has fewer than `amount` elements, all of them are returned.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, seq};
+/// #
+/// let mut rng = Sfc64::new_u64(0, None);
+/// assert_eq!(seq::sample_iter(1..=10, 3, &mut rng), [9, 2, 8]);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[inline]
+pub fn sample_iter<I: IntoIterator, R: RngCore + ?Sized>(
+    iter: I,
+    amount: usize,
+    rng: &mut R,
+) -> Vec<I::Item> {
+    let mut iter = iter.into_iter();
+    let mut reservoir = iter.by_ref().take(amount).collect::<Vec<_>>();
+    if amount != 0 && reservoir.len() == amount {
+        for (i, item) in iter.enumerate() {
+            let j = usize::sample_below(rng, amount + i + 1);
+            if let Some(slot) = reservoir.get_mut(j) {
+                *slot = item;
+            }
+        }
+    }
+    reservoir
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Sfc32, Sfc64};
+
+    static EXPECTED_SHUFFLE_SFC32: [u8; 16] =
+        [3, 14, 11, 8, 15, 10, 1, 7, 4, 9, 6, 13, 12, 2, 0, 5];
+    static EXPECTED_SHUFFLE_SFC64: [u8; 16] =
+        [10, 2, 12, 6, 13, 9, 11, 1, 3, 5, 0, 4, 7, 8, 15, 14];
+    static EXPECTED_CHOOSE_SFC64: [u8; 8] = [14, 15, 9, 9, 5, 1, 8, 6];
+    static EXPECTED_CHOOSE_ITER_SFC64: [u8; 8] = [6, 4, 5, 12, 11, 13, 13, 9];
+    #[cfg(feature = "alloc")]
+    static EXPECTED_SAMPLE_INDICES_SFC32: [usize; 8] = [29, 3, 17, 94, 21, 56, 91, 48];
+    #[cfg(feature = "alloc")]
+    static EXPECTED_SAMPLE_INDICES_SFC64: [usize; 8] = [82, 89, 55, 56, 35, 7, 52, 39];
+    #[cfg(feature = "alloc")]
+    static EXPECTED_SAMPLE_ITER_SFC32: [u8; 8] = [49, 1, 53, 3, 75, 23, 20, 19];
+    #[cfg(feature = "alloc")]
+    static EXPECTED_SAMPLE_ITER_SFC64: [u8; 8] = [64, 99, 95, 91, 78, 5, 15, 11];
+
+    #[test]
+    fn shuffle() {
+        {
+            let mut rng = Sfc32::new_u64(u64::default(), None);
+            let mut v: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+            super::shuffle(&mut v, &mut rng);
+            assert_eq!(v, EXPECTED_SHUFFLE_SFC32);
+        }
+        {
+            let mut rng = Sfc64::new_u64(u64::default(), None);
+            let mut v: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+            super::shuffle(&mut v, &mut rng);
+            assert_eq!(v, EXPECTED_SHUFFLE_SFC64);
+        }
+    }
+
+    #[test]
+    fn shuffle_short() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let expected = rng.clone();
+        super::shuffle::<u8, _>(&mut [], &mut rng);
+        super::shuffle(&mut [0], &mut rng);
+        assert_eq!(rng, expected);
+    }
+
+    #[test]
+    fn choose() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let v: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let chosen = [(); 8].map(|()| *super::choose(&v, &mut rng).unwrap());
+        assert_eq!(chosen, EXPECTED_CHOOSE_SFC64);
+
+        let expected = rng.clone();
+        assert_eq!(super::choose::<u8, _>(&[], &mut rng), None);
+        assert_eq!(rng, expected);
+    }
+
+    #[test]
+    fn choose_iter() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let chosen = [(); 8].map(|()| super::choose_iter(0..16_u8, &mut rng).unwrap());
+        assert_eq!(chosen, EXPECTED_CHOOSE_ITER_SFC64);
+        assert_eq!(super::choose_iter(0..0, &mut rng), None);
+        assert_eq!(super::choose_iter(0..1, &mut rng), Some(0));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sample_indices() {
+        {
+            let mut rng = Sfc32::new_u64(u64::default(), None);
+            assert_eq!(
+                super::sample_indices(100, 8, &mut rng),
+                EXPECTED_SAMPLE_INDICES_SFC32
+            );
+        }
+        {
+            let mut rng = Sfc64::new_u64(u64::default(), None);
+            assert_eq!(
+                super::sample_indices(100, 8, &mut rng),
+                EXPECTED_SAMPLE_INDICES_SFC64
+            );
+        }
+        {
+            let mut rng = Sfc64::new_u64(u64::default(), None);
+            let mut indices = super::sample_indices(16, 16, &mut rng);
+            indices.sort_unstable();
+            assert!(indices.into_iter().eq(0..16));
+            assert!(super::sample_indices(16, 0, &mut rng).is_empty());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "`amount` must be less than or equal to `length`")]
+    fn sample_indices_too_many() {
+        let mut rng = Sfc64::new_u64(u64::default(), None);
+        let _ = super::sample_indices(1, 2, &mut rng);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sample_iter() {
+        {
+            let mut rng = Sfc32::new_u64(u64::default(), None);
+            assert_eq!(
+                super::sample_iter(0..100_u8, 8, &mut rng),
+                EXPECTED_SAMPLE_ITER_SFC32
+            );
+        }
+        {
+            let mut rng = Sfc64::new_u64(u64::default(), None);
+            assert_eq!(
+                super::sample_iter(0..100_u8, 8, &mut rng),
+                EXPECTED_SAMPLE_ITER_SFC64
+            );
+        }
+        {
+            let mut rng = Sfc64::new_u64(u64::default(), None);
+            let expected = rng.clone();
+            assert_eq!(super::sample_iter(0..3, 8, &mut rng), [0, 1, 2]);
+            assert!(super::sample_iter(0..3, 0, &mut rng).is_empty());
+            assert_eq!(rng, expected);
+        }
+    }
+}
@@ -13,7 +13,9 @@ use serde::{Deserialize, Serialize};
 /// The sfc32 algorithm is not suitable for cryptographic uses but is very fast.
 /// This algorithm has a 128-bit state and outputs 32-bit random numbers. The
 /// average period of this algorithm is approximately 2<sup>127</sup>, and the
-/// minimum period is greater than or equal to 2<sup>32</sup>.
+/// minimum period is greater than or equal to 2<sup>32</sup>. Use
+/// [`PeriodGuarded`](crate::PeriodGuarded) to detect when the minimum period
+/// would be exceeded.
 ///
 /// The algorithm used here is translated from the reference implementation
 /// provided by [PractRand] version pre0.95, which is licensed under the [public
@@ -100,6 +102,65 @@ impl Sfc32 {
         let rounds = rounds.or(Some(12));
         Self::new(a, b, c, rounds)
     }
+
+    /// Fills `dst` with random [`u32`] values.
+    ///
+    /// The values are the same as those produced by calling
+    /// [`Sfc32::next_u32`] repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc32, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// let mut dst = [u32::default(); 2];
+    /// rng.fill_u32(&mut dst);
+    /// assert_eq!(dst, [0xfb52_c520, 0x3880_2be1]);
+    /// ```
+    #[inline]
+    pub fn fill_u32(&mut self, dst: &mut [u32]) {
+        // Step a local copy so that the state can be kept in registers.
+        let mut rng = self.clone();
+        for d in dst {
+            *d = rng.next_u32();
+        }
+        *self = rng;
+    }
+
+    /// Fills `dst` with random [`u64`] values.
+    ///
+    /// The values are the same as those produced by calling
+    /// [`Sfc32::next_u64`] repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc32, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc32::new(0, 0, 0, None);
+    /// let mut dst = [u64::default(); 1];
+    /// rng.fill_u64(&mut dst);
+    /// assert_eq!(dst, [0x3880_2be1_fb52_c520]);
+    /// ```
+    #[inline]
+    pub fn fill_u64(&mut self, dst: &mut [u64]) {
+        let mut rng = self.clone();
+        for d in dst {
+            *d = rng.next_u64();
+        }
+        *self = rng;
+    }
+
+    /// Creates a new `Sfc32` from the raw state words.
+    pub(crate) const fn from_state([a, b, c, counter]: [u32; 4]) -> Self {
+        Self { a, b, c, counter }
+    }
+
+    /// Returns the raw state words in the order `a`, `b`, `c` and `counter`.
+    pub(crate) const fn state(&self) -> [u32; 4] {
+        [self.a, self.b, self.c, self.counter]
+    }
 }
 
 impl RngCore for Sfc32 {
@@ -382,6 +443,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fill_u32() {
+        {
+            let mut rng = Sfc32::from_seed(Default::default());
+            let mut dst = [u32::default(); 16];
+            rng.fill_u32(&mut dst);
+            assert_eq!(dst, EXPECTED_1);
+        }
+        {
+            let mut rng = Sfc32::from_seed(SEED_2);
+            let mut dst = [u32::default(); 16];
+            rng.fill_u32(&mut dst);
+            assert_eq!(dst, EXPECTED_2);
+        }
+    }
+
+    #[test]
+    fn fill_u32_per_chunk() {
+        let mut rng = Sfc32::from_seed(Default::default());
+        let mut dst = [u32::default(); 3];
+        rng.fill_u32(&mut dst);
+        assert_eq!(dst, EXPECTED_1[..3]);
+        rng.fill_u32(&mut []);
+        rng.fill_u32(&mut dst);
+        assert_eq!(dst, EXPECTED_1[3..6]);
+        assert_eq!(rng.next_u32(), EXPECTED_1[6]);
+    }
+
+    #[test]
+    fn fill_u64() {
+        {
+            let mut rng = Sfc32::from_seed(Default::default());
+            let mut dst = [u64::default(); 8];
+            rng.fill_u64(&mut dst);
+            for (d, e) in dst.iter().zip(EXPECTED_1.map(u64::from).chunks_exact(2)) {
+                assert_eq!(*d, (e[1] << u32::BITS) | e[0]);
+            }
+        }
+        {
+            let mut rng = Sfc32::from_seed(SEED_2);
+            let mut dst = [u64::default(); 8];
+            rng.fill_u64(&mut dst);
+            for (d, e) in dst.iter().zip(EXPECTED_2.map(u64::from).chunks_exact(2)) {
+                assert_eq!(*d, (e[1] << u32::BITS) | e[0]);
+            }
+        }
+    }
+
     #[test]
     fn fill_bytes() {
         {
@@ -445,4 +554,14 @@ mod tests {
         assert_eq!(deserialized_rng, rng);
         assert_eq!(deserialized_rng.next_u32(), rng.next_u32());
     }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn quick_check() {
+        for seed in [u64::default(), 0x0123_4567_89ab_cdef] {
+            for result in crate::quality::quick_check(&mut Sfc32::new_u64(seed, None)) {
+                assert!(result.p_value() > 1e-6, "{result}");
+            }
+        }
+    }
 }
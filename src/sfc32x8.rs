@@ -0,0 +1,291 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! An implementation of eight independent sfc32 random number generators
+//! stepped together.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Sfc32;
+
+/// The number of lanes of [`Sfc32x8`].
+const LANES: usize = 8;
+
+/// Eight independent sfc32 random number generators stepped together.
+///
+/// Each lane is a separate [`Sfc32`] stream, and lane `i` of this generator
+/// produces exactly the same values as a [`Sfc32`] created from the seeds of
+/// lane `i`. The state is stored as one array per state word, so the compiler
+/// can step all lanes with SIMD instructions of the target. On targets without
+/// suitable SIMD instructions, the lanes are stepped one after another.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc32, Sfc32x8, rand_core::RngCore};
+/// #
+/// let mut rng = Sfc32x8::new_u64([0, 1, 2, 3, 4, 5, 6, 7], None);
+/// let x = rng.next_u32x8();
+/// assert_eq!(x[0], 0x5146_76c3);
+/// assert_eq!(x[7], Sfc32::new_u64(7, None).next_u32());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Sfc32x8 {
+    a: [u32; LANES],
+    b: [u32; LANES],
+    c: [u32; LANES],
+    counter: [u32; LANES],
+}
+
+impl Sfc32x8 {
+    /// Creates a new `Sfc32x8` using the given seeds for each lane.
+    ///
+    /// If `rounds` is [`None`], the state is mixed up 15 rounds during
+    /// initialization.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32x8;
+    /// #
+    /// let mut rng = Sfc32x8::new([0; 8], [0; 8], [0; 8], None);
+    /// assert_eq!(rng.next_u32x8(), [0xfb52_c520; 8]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(a: [u32; 8], b: [u32; 8], c: [u32; 8], rounds: Option<u32>) -> Self {
+        let mut state = Self {
+            a,
+            b,
+            c,
+            counter: [1; LANES],
+        };
+        let rounds = rounds.unwrap_or(15);
+        for _ in 0..rounds {
+            state.next_u32x8();
+        }
+        state
+    }
+
+    /// Creates a new `Sfc32x8` using a [`u64`] seed for each lane.
+    ///
+    /// If `rounds` is [`None`], the state is mixed up 12 rounds during
+    /// initialization.
+    ///
+    /// This is the lane-parallel version of [`Sfc32::new_u64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32x8;
+    /// #
+    /// let mut rng = Sfc32x8::new_u64([0; 8], None);
+    /// assert_eq!(rng.next_u32x8(), [0x5146_76c3; 8]);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    #[inline]
+    pub fn new_u64(seeds: [u64; 8], rounds: Option<u32>) -> Self {
+        let (a, b, c) = (
+            [u32::default(); LANES],
+            seeds.map(|s| s as u32),
+            seeds.map(|s| (s >> u32::BITS) as u32),
+        );
+        let rounds = rounds.or(Some(12));
+        Self::new(a, b, c, rounds)
+    }
+
+    /// Creates a new `Sfc32x8` from eight [`Sfc32`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc32, Sfc32x8, rand_core::SeedableRng};
+    /// #
+    /// let lanes = [0, 1, 2, 3, 4, 5, 6, 7].map(Sfc32::seed_from_u64);
+    /// let rng = Sfc32x8::from_lanes(lanes.clone());
+    /// assert_eq!(rng.into_lanes(), lanes);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_lanes(lanes: [Sfc32; 8]) -> Self {
+        let lanes = lanes.map(|lane| lane.state());
+        Self {
+            a: lanes.map(|s| s[0]),
+            b: lanes.map(|s| s[1]),
+            c: lanes.map(|s| s[2]),
+            counter: lanes.map(|s| s[3]),
+        }
+    }
+
+    /// Splits this `Sfc32x8` into eight [`Sfc32`]s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc32, Sfc32x8, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc32x8::new_u64([0, 1, 2, 3, 4, 5, 6, 7], None);
+    /// rng.next_u32x8();
+    /// let [_, mut lane, ..] = rng.clone().into_lanes();
+    /// assert_eq!(lane.next_u32(), rng.next_u32x8()[1]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_lanes(self) -> [Sfc32; 8] {
+        core::array::from_fn(|i| {
+            Sfc32::from_state([self.a[i], self.b[i], self.c[i], self.counter[i]])
+        })
+    }
+
+    /// Returns the next random [`u32`] of each lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32x8;
+    /// #
+    /// let mut rng = Sfc32x8::new([0; 8], [0; 8], [0; 8], None);
+    /// assert_eq!(rng.next_u32x8(), [0xfb52_c520; 8]);
+    /// assert_eq!(rng.next_u32x8(), [0x3880_2be1; 8]);
+    /// ```
+    #[inline]
+    pub fn next_u32x8(&mut self) -> [u32; 8] {
+        const ROTATION: u32 = 21;
+        const RIGHT_SHIFT: u32 = 9;
+        const LEFT_SHIFT: u32 = 3;
+
+        core::array::from_fn(|i| {
+            let tmp = self.a[i]
+                .wrapping_add(self.b[i])
+                .wrapping_add(self.counter[i]);
+            self.a[i] = self.b[i] ^ (self.b[i] >> RIGHT_SHIFT);
+            self.b[i] = self.c[i].wrapping_add(self.c[i] << LEFT_SHIFT);
+            self.c[i] = self.c[i].rotate_left(ROTATION).wrapping_add(tmp);
+            self.counter[i] = self.counter[i].wrapping_add(1);
+            tmp
+        })
+    }
+
+    /// Fills `dst` with the random [`u32`]s of each lane.
+    ///
+    /// The values are the same as those produced by calling
+    /// [`Sfc32x8::next_u32x8`] repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::Sfc32x8;
+    /// #
+    /// let mut rng = Sfc32x8::new([0; 8], [0; 8], [0; 8], None);
+    /// let mut dst = [[u32::default(); 8]; 2];
+    /// rng.fill_u32x8(&mut dst);
+    /// assert_eq!(dst[1], [0x3880_2be1; 8]);
+    /// ```
+    #[inline]
+    pub fn fill_u32x8(&mut self, dst: &mut [[u32; 8]]) {
+        // Step a local copy so that the state can be kept in registers.
+        let mut rng = self.clone();
+        for d in dst {
+            *d = rng.next_u32x8();
+        }
+        *self = rng;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::*;
+
+    const SEEDS: [u64; 8] = [
+        u64::MIN,
+        1,
+        2,
+        0x0000_0001_0000_0000,
+        0x0123_4567_89ab_cdef,
+        0xfedc_ba98_7654_3210,
+        u64::MAX - 1,
+        u64::MAX,
+    ];
+
+    #[test]
+    fn clone() {
+        let rng = Sfc32x8::new_u64(SEEDS, None);
+        assert_eq!(rng.clone(), rng);
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(Sfc32x8::new_u64(SEEDS, None), Sfc32x8::new_u64(SEEDS, None));
+        assert_ne!(
+            Sfc32x8::new_u64(SEEDS, None),
+            Sfc32x8::new_u64([u64::default(); 8], None)
+        );
+    }
+
+    #[test]
+    fn new() {
+        #[allow(clippy::cast_possible_truncation)]
+        let a = SEEDS.map(|s| s as u32);
+        let (b, c) = (a.map(u32::reverse_bits), a.map(|s| !s));
+        for rounds in [None, Some(0), Some(1), Some(20)] {
+            let mut rng = Sfc32x8::new(a, b, c, rounds);
+            let mut lanes: [_; 8] = core::array::from_fn(|i| Sfc32::new(a[i], b[i], c[i], rounds));
+            for _ in 0..64 {
+                assert_eq!(rng.next_u32x8(), lanes.each_mut().map(RngCore::next_u32));
+            }
+        }
+    }
+
+    #[test]
+    fn new_u64() {
+        for rounds in [None, Some(0), Some(1), Some(20)] {
+            let mut rng = Sfc32x8::new_u64(SEEDS, rounds);
+            let mut lanes = SEEDS.map(|s| Sfc32::new_u64(s, rounds));
+            for _ in 0..64 {
+                assert_eq!(rng.next_u32x8(), lanes.each_mut().map(RngCore::next_u32));
+            }
+        }
+    }
+
+    #[test]
+    fn from_lanes() {
+        let lanes = SEEDS.map(Sfc32::seed_from_u64);
+        let mut rng = Sfc32x8::from_lanes(lanes.clone());
+        assert_eq!(rng.clone().into_lanes(), lanes);
+
+        let mut lanes = lanes;
+        for _ in 0..64 {
+            assert_eq!(rng.next_u32x8(), lanes.each_mut().map(RngCore::next_u32));
+        }
+        assert_eq!(rng.into_lanes(), lanes);
+    }
+
+    #[test]
+    fn fill_u32x8() {
+        let mut rng = Sfc32x8::new_u64(SEEDS, None);
+        let mut expected = rng.clone();
+        let mut dst = [[u32::default(); 8]; 16];
+        rng.fill_u32x8(&mut dst);
+        for d in dst {
+            assert_eq!(d, expected.next_u32x8());
+        }
+        assert_eq!(rng, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Sfc32x8::new_u64(SEEDS, None);
+
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut deserialized_rng = serde_json::from_str::<Sfc32x8>(&json).unwrap();
+        assert_eq!(deserialized_rng, rng);
+        assert_eq!(deserialized_rng.next_u32x8(), rng.next_u32x8());
+    }
+}
@@ -99,6 +99,95 @@ impl Sfc64 {
         let rounds = rounds.or(Some(12));
         Self::new(a, b, c, rounds)
     }
+
+    /// Fills `dst` with random [`u64`] values.
+    ///
+    /// The values are the same as those produced by calling
+    /// [`Sfc64::next_u64`] repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// let mut dst = [u64::default(); 2];
+    /// rng.fill_u64(&mut dst);
+    /// assert_eq!(dst, [0xdb90_9c81_8901_599d, 0x8ffd_1953_6521_6f57]);
+    /// ```
+    #[inline]
+    pub fn fill_u64(&mut self, dst: &mut [u64]) {
+        // Step a local copy so that the state can be kept in registers.
+        let mut rng = self.clone();
+        for d in dst {
+            *d = rng.next_u64();
+        }
+        *self = rng;
+    }
+
+    /// Fills `dst` with random [`u32`] values.
+    ///
+    /// The values are the same as those produced by calling
+    /// [`Sfc64::next_u32`] repeatedly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc64::new(0, 0, 0, None);
+    /// let mut dst = [u32::default(); 2];
+    /// rng.fill_u32(&mut dst);
+    /// assert_eq!(dst, [0x8901_599d, 0x6521_6f57]);
+    /// ```
+    #[inline]
+    pub fn fill_u32(&mut self, dst: &mut [u32]) {
+        let mut rng = self.clone();
+        for d in dst {
+            *d = rng.next_u32();
+        }
+        *self = rng;
+    }
+
+    /// Creates a new `Sfc64` from the raw state words.
+    pub(crate) const fn from_state([a, b, c, counter]: [u64; 4]) -> Self {
+        Self { a, b, c, counter }
+    }
+
+    /// Returns the raw state words in the order `a`, `b`, `c` and `counter`.
+    pub(crate) const fn state(&self) -> [u64; 4] {
+        [self.a, self.b, self.c, self.counter]
+    }
+
+    /// Undoes the last call of [`Sfc64::next_u64`], and returns the value
+    /// returned by it.
+    #[cfg(any(feature = "alloc", test))]
+    pub(crate) const fn prev_u64(&mut self) -> u64 {
+        // The multiplicative inverse of 9 modulo 2^64.
+        const INV_9: u64 = 0x8e38_e38e_38e3_8e39;
+
+        let c = self.b.wrapping_mul(INV_9);
+        let mut b = self.a;
+        let mut shift = 11;
+        while shift < u64::BITS {
+            b ^= self.a >> shift;
+            shift += 11;
+        }
+        let tmp = self.c.wrapping_sub(c.rotate_left(24));
+        self.counter = self.counter.wrapping_sub(1);
+        self.a = tmp.wrapping_sub(b).wrapping_sub(self.counter);
+        self.b = b;
+        self.c = c;
+        tmp
+    }
+
+    /// XORs `word` into `a`, and then mixes up the state `rounds` rounds.
+    pub(crate) fn absorb(&mut self, word: u64, rounds: u32) {
+        self.a ^= word;
+        for _ in 0..rounds {
+            self.next_u64();
+        }
+    }
 }
 
 impl RngCore for Sfc64 {
@@ -397,6 +486,51 @@ mod tests {
         }
     }
 
+    #[allow(clippy::cast_possible_truncation)]
+    #[test]
+    fn fill_u32() {
+        {
+            let mut rng = Sfc64::from_seed(Default::default());
+            let mut dst = [u32::default(); 16];
+            rng.fill_u32(&mut dst);
+            assert_eq!(dst, EXPECTED_1.map(|e| e as u32));
+        }
+        {
+            let mut rng = Sfc64::from_seed(SEED_2);
+            let mut dst = [u32::default(); 16];
+            rng.fill_u32(&mut dst);
+            assert_eq!(dst, EXPECTED_2.map(|e| e as u32));
+        }
+    }
+
+    #[test]
+    fn fill_u64() {
+        {
+            let mut rng = Sfc64::from_seed(Default::default());
+            let mut dst = [u64::default(); 16];
+            rng.fill_u64(&mut dst);
+            assert_eq!(dst, EXPECTED_1);
+        }
+        {
+            let mut rng = Sfc64::from_seed(SEED_2);
+            let mut dst = [u64::default(); 16];
+            rng.fill_u64(&mut dst);
+            assert_eq!(dst, EXPECTED_2);
+        }
+    }
+
+    #[test]
+    fn fill_u64_per_chunk() {
+        let mut rng = Sfc64::from_seed(Default::default());
+        let mut dst = [u64::default(); 3];
+        rng.fill_u64(&mut dst);
+        assert_eq!(dst, EXPECTED_1[..3]);
+        rng.fill_u64(&mut []);
+        rng.fill_u64(&mut dst);
+        assert_eq!(dst, EXPECTED_1[3..6]);
+        assert_eq!(rng.next_u64(), EXPECTED_1[6]);
+    }
+
     #[test]
     fn fill_bytes() {
         {
@@ -433,6 +567,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn prev_u64() {
+        let mut rng = Sfc64::from_seed(SEED_2);
+        let start = rng.clone();
+        for e in EXPECTED_2 {
+            assert_eq!(rng.next_u64(), e);
+        }
+        for e in EXPECTED_2.into_iter().rev() {
+            assert_eq!(rng.prev_u64(), e);
+        }
+        assert_eq!(rng, start);
+
+        let mut rng = Sfc64::new(u64::MAX, u64::MAX, u64::MAX, Some(0));
+        rng.prev_u64();
+        assert_eq!(rng.counter, u64::default());
+        rng.next_u64();
+        assert_eq!(rng, Sfc64::new(u64::MAX, u64::MAX, u64::MAX, Some(0)));
+    }
+
     #[test]
     fn seed_type() {
         assert_eq!(
@@ -460,4 +613,28 @@ mod tests {
         assert_eq!(deserialized_rng, rng);
         assert_eq!(deserialized_rng.next_u64(), rng.next_u64());
     }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn quick_check() {
+        for seed in [u64::default(), 0x0123_4567_89ab_cdef] {
+            for result in crate::quality::quick_check(&mut Sfc64::new_u64(seed, None)) {
+                assert!(result.p_value() > 1e-6, "{result}");
+            }
+        }
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn quick_check_neighbouring_seeds() {
+        use crate::quality::{self, Interleaved};
+
+        // After 4 rounds, the generators with neighbouring seeds are only just
+        // uncorrelated, so a step function which mixes more slowly fails this.
+        // For example, the rotation of sfc32 (21) instead of 24 fails it.
+        let rngs = (0..4096).map(|seed| Sfc64::new_u64(seed, Some(4)));
+        for result in quality::quick_check(&mut Interleaved::new(rngs.collect())) {
+            assert!(result.p_value() > 1e-6, "{result}");
+        }
+    }
 }
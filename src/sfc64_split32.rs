@@ -0,0 +1,294 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A wrapper of the sfc64 random number generator which uses both halves of
+//! each output.
+
+use rand_core::{RngCore, SeedableRng, impls};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Sfc64;
+
+/// A sfc64 random number generator which splits each [`u64`] output into two
+/// [`u32`] values.
+///
+/// [`Sfc64::next_u32`] discards the upper 32 bits of each output. This wrapper
+/// instead caches the upper 32 bits, so consecutive calls of
+/// [`next_u32`](RngCore::next_u32) return the lower 32 bits of an output of the
+/// underlying [`Sfc64`] first, and then its upper 32 bits.
+///
+/// All methods of [`RngCore`] are consistent with this [`u32`] stream:
+///
+/// - [`next_u64`](RngCore::next_u64) combines the next two [`u32`] values, the
+///   first one being the lower 32 bits. If no upper half is cached, this is the
+///   same as [`Sfc64::next_u64`].
+/// - [`fill_bytes`](RngCore::fill_bytes) writes the next [`u32`] values in
+///   little-endian order. If the length of the destination is not a multiple of
+///   4, the unused bytes of the last [`u32`] value are discarded.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{
+/// #     Sfc64, Sfc64Split32,
+/// #     rand_core::{RngCore, SeedableRng},
+/// # };
+/// #
+/// let mut rng = Sfc64Split32::from_seed([0; 24]);
+/// assert_eq!(rng.next_u32(), 0x8901_599d);
+/// assert_eq!(rng.next_u32(), 0xdb90_9c81);
+/// assert_eq!(rng.next_u64(), 0x8ffd_1953_6521_6f57);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+pub struct Sfc64Split32 {
+    rng: Sfc64,
+    cached: Option<u32>,
+}
+
+impl Sfc64Split32 {
+    /// Creates a new `Sfc64Split32` which wraps the given [`Sfc64`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, Sfc64Split32, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc64Split32::new(Sfc64::new_u64(0, None));
+    /// assert_eq!(rng.next_u32(), 0xe3cc_6041);
+    /// assert_eq!(rng.next_u32(), 0x3acf_a029);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(rng: Sfc64) -> Self {
+        Self { rng, cached: None }
+    }
+
+    /// Returns the cached upper 32 bits of the last output of the underlying
+    /// [`Sfc64`], if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64Split32, rand_core::{RngCore, SeedableRng}};
+    /// #
+    /// let mut rng = Sfc64Split32::from_seed([0; 24]);
+    /// assert_eq!(rng.cached(), None);
+    /// rng.next_u32();
+    /// assert_eq!(rng.cached(), Some(0xdb90_9c81));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn cached(&self) -> Option<u32> {
+        self.cached
+    }
+
+    /// Returns the underlying [`Sfc64`].
+    ///
+    /// The cached upper 32 bits are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, Sfc64Split32};
+    /// #
+    /// let rng = Sfc64Split32::new(Sfc64::new_u64(0, None));
+    /// assert_eq!(rng.into_inner(), Sfc64::new_u64(0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn into_inner(self) -> Sfc64 {
+        self.rng
+    }
+}
+
+impl From<Sfc64> for Sfc64Split32 {
+    #[inline]
+    fn from(rng: Sfc64) -> Self {
+        Self::new(rng)
+    }
+}
+
+impl RngCore for Sfc64Split32 {
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.cached.take().unwrap_or_else(|| {
+            let x = self.rng.next_u64();
+            self.cached = Some((x >> u32::BITS) as u32);
+            x as u32
+        })
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        match self.cached.take() {
+            Some(lo) => {
+                let x = self.rng.next_u64();
+                self.cached = Some((x >> u32::BITS) as u32);
+                (x << u32::BITS) | u64::from(lo)
+            }
+            None => self.rng.next_u64(),
+        }
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+impl SeedableRng for Sfc64Split32 {
+    type Seed = <Sfc64 as SeedableRng>::Seed;
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Sfc64::from_seed(seed).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXPECTED: [u64; 4] = [
+        0xdb90_9c81_8901_599d,
+        0x8ffd_1953_6521_6f57,
+        0xe8c4_ad5e_258a_c04a,
+        0x8f8e_f2c8_9fdb_63ca,
+    ];
+
+    #[test]
+    fn clone() {
+        let rng = Sfc64Split32::from_seed(Default::default());
+        assert_eq!(rng.clone(), rng);
+    }
+
+    #[test]
+    fn debug() {
+        let mut rng = Sfc64Split32::from_seed(Default::default());
+        rng.next_u32();
+        assert_eq!(
+            format!("{rng:?}"),
+            "Sfc64Split32 { rng: Sfc64 { a: 14749750463004975849, b: 14072470573205736538, c: 14647256220482312930, counter: 20 }, cached: Some(3683687553) }"
+        );
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Sfc64Split32::new(Sfc64::from_seed(Default::default())),
+            Sfc64Split32::from_seed(Default::default())
+        );
+        assert_eq!(
+            Sfc64Split32::from(Sfc64::from_seed(Default::default())),
+            Sfc64Split32::from_seed(Default::default())
+        );
+    }
+
+    #[test]
+    fn into_inner() {
+        let mut rng = Sfc64Split32::from_seed(Default::default());
+        rng.next_u32();
+        let mut inner = rng.into_inner();
+        assert_eq!(inner.next_u64(), EXPECTED[1]);
+    }
+
+    #[test]
+    fn next_u32() {
+        let mut rng = Sfc64Split32::from_seed(Default::default());
+        for e in EXPECTED {
+            assert_eq!(u64::from(rng.next_u32()), e & u64::from(u32::MAX));
+            assert_eq!(rng.cached(), Some((e >> u32::BITS).try_into().unwrap()));
+            assert_eq!(u64::from(rng.next_u32()), e >> u32::BITS);
+            assert_eq!(rng.cached(), None);
+        }
+    }
+
+    #[test]
+    fn next_u64() {
+        {
+            let mut rng = Sfc64Split32::from_seed(Default::default());
+            for e in EXPECTED {
+                assert_eq!(rng.next_u64(), e);
+            }
+        }
+        {
+            let mut rng = Sfc64Split32::from_seed(Default::default());
+            rng.next_u32();
+            assert_eq!(rng.next_u64(), 0x6521_6f57_db90_9c81);
+            assert_eq!(rng.next_u64(), 0x258a_c04a_8ffd_1953);
+            assert_eq!(rng.next_u32(), 0xe8c4_ad5e);
+            assert_eq!(rng.next_u64(), EXPECTED[3]);
+        }
+    }
+
+    #[test]
+    fn fill_bytes() {
+        let mut expected = [u8::default(); 32];
+        Sfc64::from_seed(Default::default()).fill_bytes(&mut expected);
+        {
+            let mut rng = Sfc64Split32::from_seed(Default::default());
+            let mut dst = [u8::default(); 32];
+            rng.fill_bytes(&mut dst);
+            assert_eq!(dst, expected);
+        }
+        {
+            let mut rng = Sfc64Split32::from_seed(Default::default());
+            let mut dst = [u8::default(); 4];
+            for e in expected.chunks_exact(dst.len()) {
+                rng.fill_bytes(&mut dst);
+                assert_eq!(dst, e);
+            }
+        }
+        {
+            let mut rng = Sfc64Split32::from_seed(Default::default());
+            let mut dst = [u8::default(); 12];
+            rng.fill_bytes(&mut dst);
+            assert_eq!(dst, expected[..12]);
+            rng.fill_bytes(&mut dst[..3]);
+            assert_eq!(dst[..3], expected[12..15]);
+            rng.fill_bytes(&mut dst);
+            assert_eq!(dst, expected[16..28]);
+            assert_eq!(rng.next_u32().to_le_bytes(), expected[28..]);
+        }
+    }
+
+    #[test]
+    fn seed_type() {
+        assert_eq!(
+            core::any::type_name::<<Sfc64Split32 as SeedableRng>::Seed>(),
+            core::any::type_name::<[u8; 24]>()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut rng = Sfc64Split32::from_seed(Default::default());
+        rng.next_u32();
+
+        let json = serde_json::to_string(&rng).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rng":{"a":14749750463004975849,"b":14072470573205736538,"c":14647256220482312930,"counter":20},"cached":3683687553}"#
+        );
+
+        let mut deserialized_rng = serde_json::from_str::<Sfc64Split32>(&json).unwrap();
+        assert_eq!(deserialized_rng, rng);
+        assert_eq!(deserialized_rng.next_u32(), rng.next_u32());
+        assert_eq!(deserialized_rng.next_u32(), rng.next_u32());
+    }
+
+    #[cfg(feature = "quality")]
+    #[test]
+    fn quick_check() {
+        for result in crate::quality::quick_check(&mut Sfc64Split32::seed_from_u64(u64::default()))
+        {
+            assert!(result.p_value() > 1e-6, "{result}");
+        }
+    }
+}
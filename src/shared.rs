@@ -0,0 +1,532 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A sfc64 random number generator which can be shared between threads.
+
+#[cfg(loom)]
+use core::array;
+#[cfg(all(not(loom), not(feature = "portable-atomic")))]
+use core::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+#[cfg(not(loom))]
+use core::{array, hint::spin_loop};
+
+#[cfg(loom)]
+use loom::{
+    hint::spin_loop,
+    sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+};
+#[cfg(all(not(loom), feature = "portable-atomic"))]
+use portable_atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use rand_core::RngCore;
+
+use crate::Sfc64;
+
+/// A single state of [`SharedSfc64`] protected by a spin lock.
+#[derive(Debug)]
+struct Shard {
+    locked: AtomicBool,
+    state: [AtomicU64; 4],
+}
+
+impl Shard {
+    fn new(rng: &Sfc64) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            state: rng.state().map(AtomicU64::new),
+        }
+    }
+
+    fn load(&self) -> Sfc64 {
+        Sfc64::from_state(array::from_fn(|i| self.state[i].load(Ordering::Relaxed)))
+    }
+
+    fn try_lock(&self) -> Option<ShardGuard<'_>> {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| ShardGuard {
+                shard: self,
+                rng: self.load(),
+            })
+    }
+}
+
+/// A locked [`Shard`], which writes the state back and unlocks the shard when
+/// dropped.
+struct ShardGuard<'a> {
+    shard: &'a Shard,
+    rng: Sfc64,
+}
+
+impl Drop for ShardGuard<'_> {
+    fn drop(&mut self) {
+        for (s, w) in self.shard.state.iter().zip(self.rng.state()) {
+            s.store(w, Ordering::Relaxed);
+        }
+        self.shard.locked.store(false, Ordering::Release);
+    }
+}
+
+/// The number of backoff steps which spin before yielding to the scheduler.
+#[cfg(not(loom))]
+const SPIN_LIMIT: u32 = 6;
+
+/// Waits before retrying to lock a shard.
+///
+/// This spins 2<sup>`step`</sup> times while `step` is at most [`SPIN_LIMIT`],
+/// and then yields the current thread to the scheduler with the `std` feature,
+/// so that a preempted lock holder can run.
+#[cfg(not(loom))]
+fn backoff(step: &mut u32) {
+    if *step <= SPIN_LIMIT {
+        for _ in 0..1_u32 << *step {
+            spin_loop();
+        }
+        *step += 1;
+    } else {
+        #[cfg(feature = "std")]
+        std::thread::yield_now();
+        #[cfg(not(feature = "std"))]
+        spin_loop();
+    }
+}
+
+/// Waits before retrying to lock a shard.
+///
+/// Under loom, spinning more than once only makes the model larger.
+#[cfg(loom)]
+fn backoff(_: &mut u32) {
+    spin_loop();
+}
+
+/// A sfc64 random number generator which can be used through a shared
+/// reference from many threads.
+///
+/// This consists of `N` independent [`Sfc64`] states, called shards. Each call
+/// picks a starting shard in round-robin order, locks the first shard which is
+/// not in use by another thread, and steps it. If all shards are in use, it
+/// waits until one of them is released. Therefore, threads rarely wait for
+/// each other if `N` is at least the number of threads using this at the same
+/// time.
+///
+/// # Locking
+///
+/// Each shard is protected by a spin lock, so this is not lock-free. A thread
+/// which finds all shards in use spins with an exponential backoff, and with
+/// the `std` feature, it then yields to the scheduler with
+/// [`yield_now`](https://doc.rust-lang.org/std/thread/fn.yield_now.html) on
+/// each retry. Without the `std` feature, it keeps spinning, so if a thread
+/// holding a shard is preempted while all shards are in use, the other threads
+/// burn CPU until it runs again.
+///
+/// The locks are not reentrant. Calling a method of the same `SharedSfc64`
+/// from within [`SharedSfc64::with_rng`] uses another shard if one is free, but
+/// deadlocks if all shards are in use, which is always the case with `N = 1`.
+///
+/// # Memory ordering
+///
+/// Each shard is protected by a spin lock, which is acquired with
+/// [`Acquire`](Ordering::Acquire) ordering and released with
+/// [`Release`](Ordering::Release) ordering. So the updates of each shard are
+/// totally ordered, and no value is returned twice. The state words themselves
+/// are accessed with [`Relaxed`](Ordering::Relaxed) ordering while the lock is
+/// held. This type does not provide any other synchronization.
+///
+/// # Reproducibility
+///
+/// Each shard produces the same sequence as the [`Sfc64`] it was created from.
+/// If this is used from only one thread, the `k`-th call (counting from 0)
+/// uses the shard at index `k % N`, so the output is reproducible. If this is
+/// used from multiple threads, which shard each call uses depends on
+/// scheduling, so the values seen by each thread are not reproducible.
+///
+/// # Examples
+///
+/// ```
+/// # use std::thread;
+/// #
+/// # use sfc_prng::SharedSfc64;
+/// #
+/// let rng = SharedSfc64::new(0);
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         s.spawn(|| rng.next_u64());
+///     }
+/// });
+/// ```
+#[derive(Debug)]
+pub struct SharedSfc64<const N: usize = 8> {
+    shards: [Shard; N],
+    next: AtomicUsize,
+}
+
+impl SharedSfc64 {
+    /// Creates a new `SharedSfc64` with 8 shards using a [`u64`] seed.
+    ///
+    /// This is the same as [`SharedSfc64::with_seed`] with `N = 8`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SharedSfc64, rand_core::RngCore};
+    /// #
+    /// let rng = SharedSfc64::new(0);
+    /// assert_eq!(rng.next_u64(), Sfc64::new(0, 0, 0, None).next_u64());
+    /// assert_eq!(rng.next_u64(), Sfc64::new(0, 1, 0, None).next_u64());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self::with_seed(seed)
+    }
+}
+
+impl<const N: usize> SharedSfc64<N> {
+    /// Creates a new `SharedSfc64` with `N` shards using a [`u64`] seed.
+    ///
+    /// The shard at index `i` is created with `Sfc64::new(seed, i as u64, 0,
+    /// None)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SharedSfc64, rand_core::RngCore};
+    /// #
+    /// let rng = SharedSfc64::<2>::with_seed(0);
+    /// assert_eq!(
+    ///     rng.into_rngs(),
+    ///     [Sfc64::new(0, 0, 0, None), Sfc64::new(0, 1, 0, None)]
+    /// );
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        Self::from_rngs(array::from_fn(|i| Sfc64::new(seed, i as u64, 0, None)))
+    }
+
+    /// Creates a new `SharedSfc64` which uses the given generators as shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SharedSfc64, rand_core::RngCore};
+    /// #
+    /// let rng = SharedSfc64::from_rngs([Sfc64::new_u64(0, None)]);
+    /// assert_eq!(rng.next_u64(), 0x3acf_a029_e3cc_6041);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_rngs(rngs: [Sfc64; N]) -> Self {
+        assert!(N > 0, "the number of shards must be greater than 0");
+        Self {
+            shards: rngs.map(|rng| Shard::new(&rng)),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the current states of the shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SharedSfc64, rand_core::RngCore};
+    /// #
+    /// let rng = SharedSfc64::from_rngs([Sfc64::new_u64(0, None)]);
+    /// rng.next_u64();
+    ///
+    /// let mut expected = Sfc64::new_u64(0, None);
+    /// expected.next_u64();
+    /// assert_eq!(rng.into_rngs(), [expected]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn into_rngs(self) -> [Sfc64; N] {
+        self.shards.map(|shard| shard.load())
+    }
+
+    /// Calls `f` with one of the shards.
+    ///
+    /// The shard is locked while `f` is running, so `f` should be short. If `f`
+    /// panics, the state which `f` has reached is kept.
+    ///
+    /// `f` must not use this `SharedSfc64` again, since that deadlocks if all
+    /// shards are in use, as described in the [locking](#locking) section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{SharedSfc64, rand_core::RngCore};
+    /// #
+    /// let rng = SharedSfc64::new(0);
+    /// let mut dst = [u8::default(); 32];
+    /// rng.with_rng(|rng| rng.fill_bytes(&mut dst));
+    /// ```
+    #[inline]
+    pub fn with_rng<T>(&self, f: impl FnOnce(&mut Sfc64) -> T) -> T {
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+        let mut step = 0;
+        loop {
+            for i in 0..N {
+                if let Some(mut guard) = self.shards[start.wrapping_add(i) % N].try_lock() {
+                    return f(&mut guard.rng);
+                }
+            }
+            backoff(&mut step);
+        }
+    }
+
+    /// Returns the next random [`u32`] from one of the shards.
+    ///
+    /// This is the same as [`Sfc64::next_u32`] of that shard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::SharedSfc64;
+    /// #
+    /// let rng = SharedSfc64::new(0);
+    /// let x = rng.next_u32();
+    /// # let _ = x;
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn next_u32(&self) -> u32 {
+        self.with_rng(RngCore::next_u32)
+    }
+
+    /// Returns the next random [`u64`] from one of the shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::SharedSfc64;
+    /// #
+    /// let rng = SharedSfc64::new(0);
+    /// let x = rng.next_u64();
+    /// # let _ = x;
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn next_u64(&self) -> u64 {
+        self.with_rng(RngCore::next_u64)
+    }
+
+    /// Fills `dst` with random data from one of the shards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::SharedSfc64;
+    /// #
+    /// let rng = SharedSfc64::new(0);
+    /// let mut dst = [u8::default(); 32];
+    /// rng.fill_bytes(&mut dst);
+    /// ```
+    #[inline]
+    pub fn fill_bytes(&self, dst: &mut [u8]) {
+        self.with_rng(|rng| rng.fill_bytes(dst));
+    }
+}
+
+impl<const N: usize> RngCore for &SharedSfc64<N> {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        SharedSfc64::next_u32(self)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        SharedSfc64::next_u64(self)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        SharedSfc64::fill_bytes(self, dst);
+    }
+}
+
+#[cfg(all(test, not(loom)))]
+mod tests {
+    use std::{thread, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn with_seed() {
+        let rng = SharedSfc64::<3>::with_seed(1);
+        assert_eq!(
+            rng.into_rngs(),
+            [
+                Sfc64::new(1, 0, 0, None),
+                Sfc64::new(1, 1, 0, None),
+                Sfc64::new(1, 2, 0, None)
+            ]
+        );
+        assert_eq!(
+            SharedSfc64::new(1).into_rngs(),
+            SharedSfc64::<8>::with_seed(1).into_rngs()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of shards must be greater than 0")]
+    fn from_rngs_empty() {
+        let _ = SharedSfc64::from_rngs([]);
+    }
+
+    #[test]
+    fn round_robin() {
+        let rng = SharedSfc64::<3>::with_seed(1);
+        let mut expected = SharedSfc64::<3>::with_seed(1).into_rngs();
+        for i in 0..30 {
+            assert_eq!(rng.next_u64(), expected[i % 3].next_u64());
+        }
+        assert_eq!(rng.into_rngs(), expected);
+    }
+
+    #[test]
+    fn rng_core() {
+        let rng = SharedSfc64::from_rngs([Sfc64::new_u64(u64::default(), None)]);
+        let mut expected = Sfc64::new_u64(u64::default(), None);
+        let mut r = &rng;
+        assert_eq!(RngCore::next_u32(&mut r), expected.next_u32());
+        assert_eq!(RngCore::next_u64(&mut r), expected.next_u64());
+        let (mut dst, mut expected_dst) = ([u8::default(); 12], [u8::default(); 12]);
+        RngCore::fill_bytes(&mut r, &mut dst);
+        expected.fill_bytes(&mut expected_dst);
+        assert_eq!(dst, expected_dst);
+    }
+
+    #[test]
+    fn with_rng_panic() {
+        let rng = SharedSfc64::from_rngs([Sfc64::new_u64(u64::default(), None)]);
+        let result = std::panic::catch_unwind(|| {
+            rng.with_rng(|rng| {
+                rng.next_u64();
+                panic!();
+            })
+        });
+        assert!(result.is_err());
+
+        let mut expected = Sfc64::new_u64(u64::default(), None);
+        expected.next_u64();
+        assert_eq!(rng.next_u64(), expected.next_u64());
+    }
+
+    #[test]
+    fn nested_with_rng() {
+        let rng = SharedSfc64::<2>::with_seed(1);
+        let mut expected = SharedSfc64::<2>::with_seed(1).into_rngs();
+        let (outer, inner) = rng.with_rng(|r| (r.next_u64(), rng.next_u64()));
+        assert_eq!(outer, expected[0].next_u64());
+        assert_eq!(inner, expected[1].next_u64());
+    }
+
+    #[test]
+    fn backoff() {
+        let mut step = 0;
+        for expected in (1..=SPIN_LIMIT + 1).chain([SPIN_LIMIT + 1; 2]) {
+            super::backoff(&mut step);
+            assert_eq!(step, expected);
+        }
+    }
+
+    #[test]
+    fn concurrent() {
+        const THREADS: usize = 4;
+        const CALLS: usize = 1000;
+
+        let rng = SharedSfc64::<2>::with_seed(1);
+        let mut values = thread::scope(|s| {
+            let handles = (0..THREADS)
+                .map(|_| s.spawn(|| (0..CALLS).map(|_| rng.next_u64()).collect::<Vec<_>>()))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        let rngs = rng.into_rngs();
+
+        // Every value must come from exactly one shard stream, so the values
+        // are the prefixes of the shard streams which end at the final states.
+        let mut expected = Vec::new();
+        for (mut shard, last) in SharedSfc64::<2>::with_seed(1)
+            .into_rngs()
+            .into_iter()
+            .zip(rngs)
+        {
+            while shard != last {
+                expected.push(shard.next_u64());
+            }
+        }
+        values.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(values.len(), THREADS * CALLS);
+        assert_eq!(values, expected);
+    }
+}
+
+#[cfg(all(test, loom))]
+mod loom_tests {
+    use loom::{sync::Arc, thread};
+
+    use super::*;
+
+    #[test]
+    fn single_shard() {
+        loom::model(|| {
+            let rng = Arc::new(SharedSfc64::from_rngs([Sfc64::new_u64(1, None)]));
+            let handle = {
+                let rng = Arc::clone(&rng);
+                thread::spawn(move || rng.next_u64())
+            };
+            let x = rng.next_u64();
+            let y = handle.join().unwrap();
+
+            let mut expected = Sfc64::new_u64(1, None);
+            let expected = [expected.next_u64(), expected.next_u64()];
+            assert!([x, y] == expected || [y, x] == expected);
+        });
+    }
+
+    #[test]
+    fn two_shards() {
+        loom::model(|| {
+            let rng = Arc::new(SharedSfc64::<2>::with_seed(1));
+            let spawn = || {
+                let rng = Arc::clone(&rng);
+                thread::spawn(move || rng.next_u64())
+            };
+            let (h1, h2) = (spawn(), spawn());
+            let x = rng.next_u64();
+            let mut values = [h1.join().unwrap(), h2.join().unwrap(), x];
+            values.sort_unstable();
+            assert!(values[0] != values[1] && values[1] != values[2]);
+
+            let rngs = Arc::try_unwrap(rng).unwrap().into_rngs();
+            let consumed = rngs
+                .iter()
+                .zip(SharedSfc64::<2>::with_seed(1).into_rngs())
+                .map(|(last, mut shard)| {
+                    let mut n = 0;
+                    while shard != *last {
+                        shard.next_u64();
+                        n += 1;
+                    }
+                    n
+                })
+                .sum::<usize>();
+            assert_eq!(consumed, 3);
+        });
+    }
+}
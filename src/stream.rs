@@ -0,0 +1,414 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A random-access index of a sfc64 stream.
+
+use alloc::vec::Vec;
+
+use rand_core::{RngCore, impls};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Sfc64;
+
+/// A [`Sfc64`] stream which records snapshots of the state, so that it can
+/// jump to any output index.
+///
+/// The index of an output is the number of [`u64`] values generated before it
+/// since the `SfcStreamIndex` was created. Each of [`next_u32`],
+/// [`next_u64`] and every 8 bytes (rounded up) of [`fill_bytes`] use one
+/// output.
+///
+/// A snapshot of the state is recorded before every output whose index is a
+/// multiple of the interval, up to the current position. So there are always
+/// `position / interval + 1` snapshots. [`seek`](Self::seek) to an earlier
+/// index restores the nearest snapshot at or before it, discards the later
+/// snapshots, and then steps forward, which takes at most `interval - 1`
+/// steps. Seeking forward steps forward and records the snapshots along the
+/// way.
+///
+/// [`step_back`](Self::step_back) inverts a single step of the state without
+/// using the snapshots.
+///
+/// With the `serde` feature, a deserialized `SfcStreamIndex` is checked to
+/// have a non-zero interval and `position / interval + 1` snapshots.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, SfcStreamIndex, rand_core::RngCore};
+/// #
+/// let mut stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 1024);
+/// let values = (0..5000).map(|_| stream.next_u64()).collect::<Vec<_>>();
+///
+/// stream.seek(1234);
+/// assert_eq!(stream.next_u64(), values[1234]);
+/// assert_eq!(stream.step_back(), Some(values[1234]));
+/// assert_eq!(stream.position(), 1234);
+/// ```
+///
+/// [`next_u32`]: RngCore::next_u32
+/// [`next_u64`]: RngCore::next_u64
+/// [`fill_bytes`]: RngCore::fill_bytes
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "SfcStreamIndexRepr"))]
+pub struct SfcStreamIndex {
+    rng: Sfc64,
+    position: u64,
+    interval: u64,
+    snapshots: Vec<Sfc64>,
+}
+
+impl SfcStreamIndex {
+    /// Creates a new `SfcStreamIndex` which starts from `rng` and records a
+    /// snapshot every `interval` outputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex};
+    /// #
+    /// let stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 1024);
+    /// assert_eq!(stream.position(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new(rng: Sfc64, interval: u64) -> Self {
+        assert_ne!(interval, 0, "the interval must be greater than 0");
+        Self {
+            snapshots: alloc::vec![rng.clone()],
+            rng,
+            position: 0,
+            interval,
+        }
+    }
+
+    /// Returns the index of the next output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex, rand_core::RngCore};
+    /// #
+    /// let mut stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 1024);
+    /// stream.next_u64();
+    /// assert_eq!(stream.position(), 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the number of outputs between the snapshots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex};
+    /// #
+    /// let stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 1024);
+    /// assert_eq!(stream.interval(), 1024);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Returns the recorded snapshots.
+    ///
+    /// The snapshot at index `i` is the state before the output at index `i *
+    /// interval`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex};
+    /// #
+    /// let mut stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 4);
+    /// stream.seek(10);
+    /// assert_eq!(stream.snapshots().len(), 3);
+    /// assert_eq!(stream.snapshots()[0], Sfc64::new_u64(0, None));
+    /// stream.seek(5);
+    /// assert_eq!(stream.snapshots().len(), 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn snapshots(&self) -> &[Sfc64] {
+        &self.snapshots
+    }
+
+    /// Returns the current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex};
+    /// #
+    /// let stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 1024);
+    /// assert_eq!(stream.rng(), &Sfc64::new_u64(0, None));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn rng(&self) -> &Sfc64 {
+        &self.rng
+    }
+
+    /// Moves to the output at index `k`.
+    ///
+    /// If `k` is less than the current position, the snapshots after `k` are
+    /// discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex, rand_core::RngCore};
+    /// #
+    /// let mut rng = Sfc64::new_u64(0, None);
+    /// for _ in 0..100 {
+    ///     rng.next_u64();
+    /// }
+    ///
+    /// let mut stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 16);
+    /// stream.seek(100);
+    /// assert_eq!(stream.rng(), &rng);
+    /// stream.seek(0);
+    /// assert_eq!(stream.rng(), &Sfc64::new_u64(0, None));
+    /// ```
+    #[inline]
+    pub fn seek(&mut self, k: u64) {
+        if k < self.position {
+            let i = k / self.interval;
+            #[allow(clippy::cast_possible_truncation)]
+            self.snapshots.truncate(i as usize + 1);
+            self.rng = self.snapshots[self.snapshots.len() - 1].clone();
+            self.position = i * self.interval;
+        }
+        while self.position < k {
+            self.next_u64();
+        }
+    }
+
+    /// Moves back to the previous output, and returns it.
+    ///
+    /// This inverts a single step of the state, so it does not need the
+    /// snapshots. Returns [`None`] if the position is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use sfc_prng::{Sfc64, SfcStreamIndex, rand_core::RngCore};
+    /// #
+    /// let mut stream = SfcStreamIndex::new(Sfc64::new_u64(0, None), 1024);
+    /// let x = stream.next_u64();
+    /// assert_eq!(stream.step_back(), Some(x));
+    /// assert_eq!(stream.step_back(), None);
+    /// ```
+    #[inline]
+    pub fn step_back(&mut self) -> Option<u64> {
+        let position = self.position.checked_sub(1)?;
+        if self.position % self.interval == 0 {
+            self.snapshots.pop();
+        }
+        self.position = position;
+        Some(self.rng.prev_u64())
+    }
+}
+
+impl RngCore for SfcStreamIndex {
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let value = self.rng.next_u64();
+        self.position += 1;
+        if self.position % self.interval == 0 {
+            self.snapshots.push(self.rng.clone());
+        }
+        value
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+/// The serialized form of [`SfcStreamIndex`], which is checked before being
+/// converted.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SfcStreamIndexRepr {
+    rng: Sfc64,
+    position: u64,
+    interval: u64,
+    snapshots: Vec<Sfc64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SfcStreamIndexRepr> for SfcStreamIndex {
+    type Error = &'static str;
+
+    fn try_from(repr: SfcStreamIndexRepr) -> Result<Self, Self::Error> {
+        if repr.interval == 0 {
+            return Err("the interval must be greater than 0");
+        }
+        if repr.snapshots.is_empty() {
+            return Err("there must be at least one snapshot");
+        }
+        if repr.snapshots.len() as u64 != repr.position / repr.interval + 1 {
+            return Err("the number of snapshots does not match the position");
+        }
+        Ok(Self {
+            rng: repr.rng,
+            position: repr.position,
+            interval: repr.interval,
+            snapshots: repr.snapshots,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use alloc::string::ToString;
+
+    use rand_core::SeedableRng;
+
+    use super::*;
+
+    fn outputs(n: usize) -> Vec<u64> {
+        let mut rng = Sfc64::from_seed(Default::default());
+        (0..n).map(|_| rng.next_u64()).collect()
+    }
+
+    #[test]
+    fn next_u64() {
+        let expected = outputs(100);
+        let mut stream = SfcStreamIndex::new(Sfc64::from_seed(Default::default()), 7);
+        for (i, e) in expected.iter().enumerate() {
+            assert_eq!(stream.position(), i as u64);
+            assert_eq!(stream.next_u64(), *e);
+        }
+        assert_eq!(stream.snapshots().len(), 15);
+        let mut rng = Sfc64::from_seed(Default::default());
+        for (i, snapshot) in stream.snapshots().iter().enumerate() {
+            assert_eq!(snapshot, &rng);
+            if i != 14 {
+                for _ in 0..7 {
+                    rng.next_u64();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn next_u32_and_fill_bytes() {
+        let mut stream = SfcStreamIndex::new(Sfc64::from_seed(Default::default()), 7);
+        let mut rng = Sfc64::from_seed(Default::default());
+        assert_eq!(stream.next_u32(), rng.next_u32());
+        let (mut dst, mut expected) = ([u8::default(); 20], [u8::default(); 20]);
+        stream.fill_bytes(&mut dst);
+        rng.fill_bytes(&mut expected);
+        assert_eq!(dst, expected);
+        assert_eq!(stream.position(), 4);
+        assert_eq!(stream.rng(), &rng);
+    }
+
+    #[test]
+    fn seek() {
+        let expected = outputs(200);
+        let mut stream = SfcStreamIndex::new(Sfc64::from_seed(Default::default()), 16);
+        for k in [150, 0, 17, 16, 15, 199, 64, 63, 150] {
+            stream.seek(k as u64);
+            assert_eq!(stream.position(), k as u64);
+            assert_eq!(stream.next_u64(), expected[k]);
+            assert_eq!(stream.snapshots().len() as u64, stream.position() / 16 + 1);
+        }
+        assert_eq!(stream.snapshots().len(), 10);
+    }
+
+    #[test]
+    fn seek_forward_from_current() {
+        let expected = outputs(50);
+        let mut stream = SfcStreamIndex::new(Sfc64::from_seed(Default::default()), 16);
+        stream.seek(40);
+        stream.seek(45);
+        assert_eq!(stream.next_u64(), expected[45]);
+        assert_eq!(stream.snapshots().len(), 3);
+    }
+
+    #[test]
+    fn step_back() {
+        let expected = outputs(40);
+        let mut stream = SfcStreamIndex::new(Sfc64::from_seed(Default::default()), 16);
+        stream.seek(40);
+        for (i, e) in expected.iter().enumerate().rev() {
+            assert_eq!(stream.step_back(), Some(*e));
+            assert_eq!(stream.position(), i as u64);
+        }
+        assert_eq!(stream.rng(), &Sfc64::from_seed(Default::default()));
+        assert_eq!(stream.step_back(), None);
+        assert_eq!(stream.position(), 0);
+        assert_eq!(stream.snapshots(), [Sfc64::from_seed(Default::default())]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the interval must be greater than 0")]
+    fn new_with_zero_interval() {
+        let _ = SfcStreamIndex::new(Sfc64::from_seed(Default::default()), 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let mut stream = SfcStreamIndex::new(Sfc64::new(1, 2, 3, Some(0)), 2);
+        stream.next_u64();
+
+        let json = serde_json::to_string(&stream).unwrap();
+        assert_eq!(
+            json,
+            r#"{"rng":{"a":2,"b":27,"c":50331652,"counter":2},"position":1,"interval":2,"snapshots":[{"a":1,"b":2,"c":3,"counter":1}]}"#
+        );
+
+        let mut deserialized = serde_json::from_str::<SfcStreamIndex>(&json).unwrap();
+        assert_eq!(deserialized, stream);
+        deserialized.seek(0);
+        stream.seek(0);
+        assert_eq!(deserialized.next_u64(), stream.next_u64());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid() {
+        let snapshot = r#"{"a":1,"b":2,"c":3,"counter":1}"#;
+        for (position, interval, snapshots, message) in [
+            (0, 0, snapshot, "the interval must be greater than 0"),
+            (0, 2, "", "there must be at least one snapshot"),
+            (
+                2,
+                2,
+                snapshot,
+                "the number of snapshots does not match the position",
+            ),
+        ] {
+            let json = format!(
+                r#"{{"rng":{snapshot},"position":{position},"interval":{interval},"snapshots":[{snapshots}]}}"#
+            );
+            let error = serde_json::from_str::<SfcStreamIndex>(&json).unwrap_err();
+            assert_eq!(error.to_string(), message);
+        }
+    }
+}
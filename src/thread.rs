@@ -0,0 +1,289 @@
+// SPDX-FileCopyrightText: 2025 Shun Sakai
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A thread-local random number generator.
+
+use core::cell::RefCell;
+use std::{env, ffi::OsStr, process, thread_local};
+
+use rand_core::{RngCore, SeedableRng};
+
+use crate::Sfc64;
+
+/// The name of the environment variable which forces a fixed seed for the
+/// thread-local generators.
+///
+/// If this is set to a [`u64`] value in decimal, the thread-local generator of
+/// each thread is initialized by [`Sfc64::new_u64`] with that value instead of
+/// the seed from the operating system. This is useful for reproducible test
+/// runs. Note that all threads then produce the same sequence.
+pub const SEED_ENV_VAR: &str = "SFC_PRNG_SEED";
+
+/// The thread-local generator and the ID of the process which initialized it.
+#[derive(Debug)]
+struct State {
+    rng: Sfc64,
+    pid: u32,
+}
+
+thread_local! {
+    static THREAD_RNG: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Creates the initial generator from the value of [`SEED_ENV_VAR`].
+fn initial_rng(seed: Option<&OsStr>) -> Sfc64 {
+    seed.map_or_else(Sfc64::from_os_rng, |seed| {
+        let seed = seed
+            .to_str()
+            .and_then(|s| s.parse().ok())
+            .expect("`SFC_PRNG_SEED` must be a `u64` value");
+        Sfc64::new_u64(seed, None)
+    })
+}
+
+/// Calls `f` with the thread-local generator of the current thread.
+///
+/// The generator is created on the first use in each thread. It is seeded from
+/// the operating system, unless [`SEED_ENV_VAR`] is set or [`seed_thread_rng`]
+/// has been called.
+///
+/// The generator also remembers the ID of the process which created it. If the
+/// process ID has changed, for example in the child process after `fork`, the
+/// generator is created again so that the parent and the child do not produce
+/// the same sequence. This check gets the process ID on each call, which is a
+/// system call on most platforms.
+///
+/// # Panics
+///
+/// Panics if this is called from within `f`, if [`SEED_ENV_VAR`] is set to an
+/// invalid value, or if the seed from the operating system cannot be obtained.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{rand_core::RngCore, with_thread_rng};
+/// #
+/// let x = with_thread_rng(|rng| rng.next_u64());
+/// # let _ = x;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn with_thread_rng<T>(f: impl FnOnce(&mut Sfc64) -> T) -> T {
+    THREAD_RNG.with_borrow_mut(|state| {
+        let pid = process::id();
+        if state.as_ref().is_some_and(|s| s.pid != pid) {
+            *state = None;
+        }
+        let state = state.get_or_insert_with(|| State {
+            rng: initial_rng(env::var_os(SEED_ENV_VAR).as_deref()),
+            pid,
+        });
+        f(&mut state.rng)
+    })
+}
+
+/// Replaces the thread-local generator of the current thread with
+/// [`Sfc64::new_u64`] using `seed`.
+///
+/// Other threads are not affected. If the process ID changes later, the
+/// generator is created again as described in [`with_thread_rng`].
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, rand_core::RngCore, seed_thread_rng, with_thread_rng};
+/// #
+/// seed_thread_rng(0);
+/// assert_eq!(
+///     with_thread_rng(|rng| rng.next_u64()),
+///     Sfc64::new_u64(0, None).next_u64()
+/// );
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn seed_thread_rng(seed: u64) {
+    THREAD_RNG.set(Some(State {
+        rng: Sfc64::new_u64(seed, None),
+        pid: process::id(),
+    }));
+}
+
+/// Discards the thread-local generator of the current thread.
+///
+/// The next use creates it again as described in [`with_thread_rng`].
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{reseed_thread_rng, seed_thread_rng};
+/// #
+/// seed_thread_rng(0);
+/// reseed_thread_rng();
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[inline]
+pub fn reseed_thread_rng() {
+    THREAD_RNG.set(None);
+}
+
+/// Returns a handle to the thread-local generator.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{rand_core::RngCore, thread_rng};
+/// #
+/// let mut rng = thread_rng();
+/// let x = rng.next_u64();
+/// # let _ = x;
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use]
+#[inline]
+pub const fn thread_rng() -> ThreadRng {
+    ThreadRng(())
+}
+
+/// A handle to the thread-local generator.
+///
+/// Each method of [`RngCore`] calls [`with_thread_rng`], so this always uses
+/// the generator of the current thread, and a handle from before `fork` uses a
+/// new generator in the child process.
+///
+/// # Examples
+///
+/// ```
+/// # use sfc_prng::{Sfc64, ThreadRng, rand_core::RngCore, seed_thread_rng};
+/// #
+/// seed_thread_rng(0);
+/// let mut rng = ThreadRng::default();
+/// assert_eq!(rng.next_u64(), Sfc64::new_u64(0, None).next_u64());
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadRng(());
+
+impl RngCore for ThreadRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        with_thread_rng(RngCore::next_u32)
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        with_thread_rng(RngCore::next_u64)
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        with_thread_rng(|rng| rng.fill_bytes(dst));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn initial_rng() {
+        assert_eq!(
+            super::initial_rng(Some(OsStr::new("42"))),
+            Sfc64::new_u64(42, None)
+        );
+        assert_eq!(
+            super::initial_rng(Some(OsStr::new("18446744073709551615"))),
+            Sfc64::new_u64(u64::MAX, None)
+        );
+        assert_ne!(super::initial_rng(None), super::initial_rng(None));
+    }
+
+    #[test]
+    #[should_panic(expected = "`SFC_PRNG_SEED` must be a `u64` value")]
+    fn initial_rng_invalid() {
+        let _ = super::initial_rng(Some(OsStr::new("-1")));
+    }
+
+    #[test]
+    fn seed_thread_rng() {
+        super::seed_thread_rng(1);
+        let mut expected = Sfc64::new_u64(1, None);
+        for _ in 0..4 {
+            assert_eq!(with_thread_rng(RngCore::next_u64), expected.next_u64());
+        }
+        let mut rng = thread_rng();
+        assert_eq!(rng.next_u32(), expected.next_u32());
+        let (mut dst, mut expected_dst) = ([u8::default(); 12], [u8::default(); 12]);
+        rng.fill_bytes(&mut dst);
+        expected.fill_bytes(&mut expected_dst);
+        assert_eq!(dst, expected_dst);
+    }
+
+    #[test]
+    fn seed_thread_rng_is_thread_local() {
+        super::seed_thread_rng(1);
+        thread::spawn(|| {
+            super::seed_thread_rng(2);
+            assert_eq!(
+                with_thread_rng(RngCore::next_u64),
+                Sfc64::new_u64(2, None).next_u64()
+            );
+        })
+        .join()
+        .unwrap();
+        assert_eq!(
+            with_thread_rng(RngCore::next_u64),
+            Sfc64::new_u64(1, None).next_u64()
+        );
+    }
+
+    #[test]
+    fn reseed_thread_rng() {
+        super::seed_thread_rng(1);
+        super::reseed_thread_rng();
+        assert!(THREAD_RNG.with_borrow(Option::is_none));
+        with_thread_rng(RngCore::next_u64);
+        assert!(THREAD_RNG.with_borrow(Option::is_some));
+    }
+
+    /// Pretends that the thread-local generator was created by another
+    /// process, as in the child process after `fork`.
+    fn fake_fork() {
+        THREAD_RNG.with_borrow_mut(|state| {
+            let state = state.as_mut().unwrap();
+            state.pid = state.pid.wrapping_add(1);
+        });
+    }
+
+    #[test]
+    fn pid_change() {
+        super::seed_thread_rng(1);
+        fake_fork();
+        let rng = with_thread_rng(|rng| rng.clone());
+        assert_ne!(rng, Sfc64::new_u64(1, None));
+        assert_eq!(
+            THREAD_RNG.with_borrow(|state| state.as_ref().unwrap().pid),
+            process::id()
+        );
+    }
+
+    #[test]
+    fn pid_change_with_handle() {
+        let mut rng = ThreadRng::default();
+        super::seed_thread_rng(1);
+        fake_fork();
+        assert_ne!(rng.next_u64(), Sfc64::new_u64(1, None).next_u64());
+        assert_eq!(
+            THREAD_RNG.with_borrow(|state| state.as_ref().unwrap().pid),
+            process::id()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "already")]
+    fn reentrant() {
+        with_thread_rng(|_| with_thread_rng(RngCore::next_u64));
+    }
+}